@@ -0,0 +1,119 @@
+//! Approximate equality of float tuples with [`approx`](::approx).
+//!
+//! approx doesn't implement its traits for tuples, and as they're foreign to
+//! this crate they can't be implemented for tuples here either. Instead
+//! [`ApproxTuple`] wraps a tuple and implements `AbsDiffEq`, `RelativeEq` and
+//! `UlpsEq` elementwise, so it can be used with approx's assertion macros. All
+//! the elements must use the same epsilon type, such as a tuple of `f64`s.
+//! ```rust
+//! # use approx::assert_relative_eq;
+//! # use tuplestructops::approx::ApproxTuple;
+//! let a = (0.1 + 0.2, 1.0f64 / 3.0);
+//! let b = (0.3, 0.333_333_333_333_333_3);
+//! assert_relative_eq!(ApproxTuple(a), ApproxTuple(b));
+//! assert_relative_eq!(ApproxTuple(a), ApproxTuple((0.3, 0.33)), epsilon = 0.01);
+//! ```
+use std::fmt::{self, Debug, Formatter};
+
+use ::approx::{AbsDiffEq, RelativeEq, UlpsEq};
+
+use super::*;
+
+/// Wrapper implementing approx's traits elementwise for a tuple of any size.
+///
+/// Two tuples are approximately equal if every pair of corresponding elements
+/// is. It also implements `PartialEq` and `Debug`, which approx requires, for
+/// tuples of any size.
+#[derive(Clone, Copy, Default)]
+pub struct ApproxTuple<T>(pub T);
+
+impl<T: TuplePartialEq> PartialEq for ApproxTuple<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.eq_tuple(&other.0)
+    }
+}
+
+impl<T: TupleDebug> Debug for ApproxTuple<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.0.fmt_debug(f)
+    }
+}
+
+macro_rules! impl_approx {
+    (0) => {
+        #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+        impl AbsDiffEq for ApproxTuple<()> {
+            type Epsilon = f64;
+
+            fn default_epsilon() -> f64 {
+                f64::default_epsilon()
+            }
+
+            fn abs_diff_eq(&self, _other: &Self, _epsilon: f64) -> bool {
+                true
+            }
+        }
+
+        #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+        impl RelativeEq for ApproxTuple<()> {
+            fn default_max_relative() -> f64 {
+                f64::default_max_relative()
+            }
+
+            fn relative_eq(&self, _other: &Self, _epsilon: f64, _max_relative: f64) -> bool {
+                true
+            }
+        }
+
+        #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+        impl UlpsEq for ApproxTuple<()> {
+            fn default_max_ulps() -> u32 {
+                f64::default_max_ulps()
+            }
+
+            fn ulps_eq(&self, _other: &Self, _epsilon: f64, _max_ulps: u32) -> bool {
+                true
+            }
+        }
+    };
+    ($n:literal) => {
+        seq!(J in 0..$n {
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<E: Copy, #(T~J: AbsDiffEq<Epsilon = E>,)*> AbsDiffEq for ApproxTuple<(#(T~J,)*)> {
+                type Epsilon = E;
+
+                fn default_epsilon() -> E {
+                    T0::default_epsilon()
+                }
+
+                fn abs_diff_eq(&self, other: &Self, epsilon: E) -> bool {
+                    #(self.0.J.abs_diff_eq(&other.0.J, epsilon) &&)* true
+                }
+            }
+
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<E: Copy, #(T~J: RelativeEq<Epsilon = E>,)*> RelativeEq for ApproxTuple<(#(T~J,)*)> {
+                fn default_max_relative() -> E {
+                    T0::default_max_relative()
+                }
+
+                fn relative_eq(&self, other: &Self, epsilon: E, max_relative: E) -> bool {
+                    #(self.0.J.relative_eq(&other.0.J, epsilon, max_relative) &&)* true
+                }
+            }
+
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<E: Copy, #(T~J: UlpsEq<Epsilon = E>,)*> UlpsEq for ApproxTuple<(#(T~J,)*)> {
+                fn default_max_ulps() -> u32 {
+                    T0::default_max_ulps()
+                }
+
+                fn ulps_eq(&self, other: &Self, epsilon: E, max_ulps: u32) -> bool {
+                    #(self.0.J.ulps_eq(&other.0.J, epsilon, max_ulps) &&)* true
+                }
+            }
+        });
+    };
+}
+
+for_each_tuple!(impl_approx);
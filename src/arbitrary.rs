@@ -0,0 +1,84 @@
+//! Structured fuzzing input of tuples of any size with
+//! [`arbitrary`](::arbitrary).
+//!
+//! arbitrary implements `Arbitrary` for tuples of up to 26 elements, but fuzz
+//! targets also need their input to implement `Debug`, which the standard
+//! library limits to 12. [`ArbitraryTuple`] wraps a tuple of any size this
+//! crate supports and implements both, so a fuzz target can take a wide tuple
+//! directly:
+//! ```rust
+//! # use arbitrary::{Arbitrary, Unstructured};
+//! # use tuplestructops::arbitrary::ArbitraryTuple;
+//! type Wide = (u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, bool);
+//!
+//! // With libfuzzer-sys: fuzz_target!(|input: ArbitraryTuple<Wide>| { ... });
+//! let data = [7; 32];
+//! let ArbitraryTuple(t) = ArbitraryTuple::<Wide>::arbitrary(&mut Unstructured::new(&data)).unwrap();
+//! assert_eq!(t.0, 7);
+//! ```
+use std::fmt::{self, Debug, Formatter};
+
+use ::arbitrary::{size_hint, Arbitrary, MaxRecursionReached, Result, Unstructured};
+
+use super::*;
+
+/// Wrapper implementing arbitrary's `Arbitrary` and `Debug` for a tuple of any
+/// size.
+///
+/// The elements are generated in order, with the last element taking the rest
+/// of the input in `arbitrary_take_rest`, in the same way as arbitrary's own
+/// impls for tuples.
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ArbitraryTuple<T>(pub T);
+
+impl<T> ArbitraryTuple<T> {
+    /// Unwrap the tuple.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T: TupleDebug> Debug for ArbitraryTuple<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.0.fmt_debug(f)
+    }
+}
+
+macro_rules! impl_arbitrary {
+    ($n:literal) => {
+        seq!(J in 0..$n {
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<'a, #(T~J: Arbitrary<'a>,)*> Arbitrary<'a> for ArbitraryTuple<(#(T~J,)*)> {
+                #[allow(unused_variables)]
+                fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+                    Ok(ArbitraryTuple((#(T~J::arbitrary(u)?,)*)))
+                }
+
+                #[allow(unused_mut, unused_variables)]
+                fn arbitrary_take_rest(mut u: Unstructured<'a>) -> Result<Self> {
+                    Ok(ArbitraryTuple((#(
+                        if J + 1 == $n {
+                            let rest = std::mem::replace(&mut u, Unstructured::new(&[]));
+                            T~J::arbitrary_take_rest(rest)?
+                        } else {
+                            T~J::arbitrary(&mut u)?
+                        },
+                    )*)))
+                }
+
+                #[inline]
+                fn size_hint(depth: usize) -> (usize, Option<usize>) {
+                    Self::try_size_hint(depth).unwrap_or_default()
+                }
+
+                #[inline]
+                #[allow(unused_variables)]
+                fn try_size_hint(depth: usize) -> Result<(usize, Option<usize>), MaxRecursionReached> {
+                    Ok(size_hint::and_all(&[#(T~J::try_size_hint(depth)?,)*]))
+                }
+            }
+        });
+    };
+}
+
+for_each_tuple!(impl_arbitrary);
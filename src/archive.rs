@@ -0,0 +1,106 @@
+//! Zero-copy archiving of tuples of any size with [`rkyv`].
+//!
+//! rkyv only implements `Archive`, `Serialize` and `Deserialize` for tuples of
+//! up to 13 elements. [`AsTuple`] is a wrapper type for rkyv's `with`
+//! attribute which archives a tuple of any size this crate supports as one of
+//! the [`ArchivedTuple0`] ... `ArchivedTupleN` structs:
+//! ```rust
+//! # use rkyv::{Archive, Deserialize, Serialize};
+//! # use tuplestructops::archive::AsTuple;
+//! type Cols = (u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8);
+//!
+//! #[derive(Archive, Serialize, Deserialize)]
+//! struct Row {
+//!     #[rkyv(with = AsTuple)]
+//!     cols: Cols,
+//! }
+//!
+//! let row = Row { cols: (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15) };
+//! let bytes = rkyv::to_bytes::<rkyv::rancor::Error>(&row).unwrap();
+//! let archived = rkyv::access::<ArchivedRow, rkyv::rancor::Error>(&bytes).unwrap();
+//! assert_eq!(archived.cols.15, 15);
+//!
+//! let back: Row = rkyv::deserialize::<_, rkyv::rancor::Error>(archived).unwrap();
+//! assert_eq!(back.cols.15, 15);
+//! ```
+use std::ptr::addr_of_mut;
+
+use rkyv::rancor::Fallible;
+use rkyv::with::{ArchiveWith, DeserializeWith, SerializeWith};
+use rkyv::{Archive, Deserialize, Place, Portable, Serialize};
+
+use super::*;
+
+/// Wrapper type for `#[rkyv(with = AsTuple)]`, to archive a tuple of any size.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AsTuple;
+
+macro_rules! impl_archive {
+    ($n:literal) => {
+        seq!(N in $n..=$n { #( seq!(J in 0..N {
+            /// An archived tuple, produced by [`AsTuple`].
+            #[derive(Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Portable)]
+            #[derive(rkyv::bytecheck::CheckBytes)]
+            #[bytecheck(crate = rkyv::bytecheck)]
+            #[rkyv(crate = rkyv)]
+            #[repr(C)]
+            pub struct ArchivedTuple~N<#(T~J,)*>(#(pub T~J,)*);
+
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<#(T~J: Archive,)*> ArchiveWith<(#(T~J,)*)> for AsTuple {
+                type Archived = ArchivedTuple~N<#(T~J::Archived,)*>;
+                type Resolver = (#(T~J::Resolver,)*);
+
+                #[allow(unused_variables)]
+                fn resolve_with(
+                    field: &(#(T~J,)*),
+                    resolver: Self::Resolver,
+                    out: Place<Self::Archived>,
+                ) {
+                    // SAFETY: This pointer is only used to project to each of
+                    // the fields, which are wrapped in a `Place` again.
+                    let out_ptr = unsafe { out.ptr() };
+                    #(
+                        // SAFETY: `out_ptr` is properly aligned and
+                        // dereferenceable.
+                        let ptr = unsafe { addr_of_mut!((*out_ptr).J) };
+                        // SAFETY: `ptr` points to field `J` of `out`, and is
+                        // properly aligned and dereferenceable.
+                        let out_field = unsafe { Place::from_field_unchecked(out, ptr) };
+                        field.J.resolve(resolver.J, out_field);
+                    )*
+                }
+            }
+
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<#(T~J: Serialize<S>,)* S: Fallible + ?Sized> SerializeWith<(#(T~J,)*), S>
+                for AsTuple
+            {
+                #[allow(unused_variables, clippy::unused_unit)]
+                fn serialize_with(
+                    field: &(#(T~J,)*),
+                    serializer: &mut S,
+                ) -> Result<Self::Resolver, S::Error> {
+                    Ok((#(field.J.serialize(serializer)?,)*))
+                }
+            }
+
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<#(T~J: Archive,)* D: Fallible + ?Sized>
+                DeserializeWith<ArchivedTuple~N<#(T~J::Archived,)*>, (#(T~J,)*), D> for AsTuple
+            where
+                #(T~J::Archived: Deserialize<T~J, D>,)*
+            {
+                #[allow(unused_variables, clippy::unused_unit)]
+                fn deserialize_with(
+                    field: &ArchivedTuple~N<#(T~J::Archived,)*>,
+                    deserializer: &mut D,
+                ) -> Result<(#(T~J,)*), D::Error> {
+                    Ok((#(field.J.deserialize(deserializer)?,)*))
+                }
+            }
+        }); )* });
+    };
+}
+
+for_each_tuple!(impl_archive);
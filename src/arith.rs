@@ -0,0 +1,394 @@
+use std::ops::{Add, Div, Mul, Sub};
+
+use super::*;
+
+/// Elementwise addition of tuples of the same length.
+pub trait TupleAdd<RHS>: seal::Sealed {
+    /// Tuple of sums.
+    type Output;
+
+    /// Add each element of `other` to the corresponding element of `self`.
+    /// ```rust
+    /// # use tuplestructops::TupleAdd;
+    /// assert_eq!((1, 2.5).add_each((10, 0.5)), (11, 3.0));
+    /// assert_eq!((&(1, 2)).add_each(&(3, 4)), (4, 6));
+    /// ```
+    fn add_each(self, other: RHS) -> Self::Output;
+}
+
+/// Elementwise subtraction of tuples of the same length.
+pub trait TupleSub<RHS>: seal::Sealed {
+    /// Tuple of differences.
+    type Output;
+
+    /// Subtract each element of `other` from the corresponding element of
+    /// `self`.
+    /// ```rust
+    /// # use tuplestructops::TupleSub;
+    /// assert_eq!((10, 2.5).sub_each((1, 0.5)), (9, 2.0));
+    /// ```
+    fn sub_each(self, other: RHS) -> Self::Output;
+}
+
+/// Elementwise multiplication of tuples of the same length.
+pub trait TupleMul<RHS>: seal::Sealed {
+    /// Tuple of products.
+    type Output;
+
+    /// Multiply each element of `self` by the corresponding element of
+    /// `other`.
+    /// ```rust
+    /// # use tuplestructops::TupleMul;
+    /// assert_eq!((2, 1.5).mul_each((3, 2.0)), (6, 3.0));
+    /// ```
+    fn mul_each(self, other: RHS) -> Self::Output;
+}
+
+/// Elementwise division of tuples of the same length.
+pub trait TupleDiv<RHS>: seal::Sealed {
+    /// Tuple of quotients.
+    type Output;
+
+    /// Divide each element of `self` by the corresponding element of `other`.
+    /// ```rust
+    /// # use tuplestructops::TupleDiv;
+    /// assert_eq!((7, 3.0).div_each((2, 2.0)), (3, 1.5));
+    /// ```
+    fn div_each(self, other: RHS) -> Self::Output;
+}
+
+macro_rules! impl_arith_op {
+    ($n:literal, $trait:ident, $method:ident, $op:ident, $opmethod:ident) => {
+        seq!(J in 0..$n {
+            // Operate by value
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<#(L~J, R~J,)*> $trait<(#(R~J,)*)> for (#(L~J,)*)
+            where
+                #(L~J: $op<R~J>,)*
+            {
+                type Output = (#(<L~J as $op<R~J>>::Output,)*);
+
+                #[allow(clippy::unused_unit, non_snake_case)]
+                fn $method(self, other: (#(R~J,)*)) -> Self::Output {
+                    let (#(L~J,)*) = self;
+                    let (#(R~J,)*) = other;
+
+                    (#(L~J.$opmethod(R~J),)*)
+                }
+            }
+
+            // Operate by reference
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<'a, #(L~J, R~J,)*> $trait<&'a (#(R~J,)*)> for &'a (#(L~J,)*)
+            where
+                #(&'a L~J: $op<&'a R~J>,)*
+            {
+                type Output = (#(<&'a L~J as $op<&'a R~J>>::Output,)*);
+
+                #[allow(clippy::unused_unit, non_snake_case)]
+                fn $method(self, other: &'a (#(R~J,)*)) -> Self::Output {
+                    let (#(L~J,)*) = self;
+                    let (#(R~J,)*) = other;
+
+                    (#(L~J.$opmethod(R~J),)*)
+                }
+            }
+        });
+    };
+}
+
+macro_rules! impl_arith {
+    ($n:literal) => {
+        impl_arith_op!($n, TupleAdd, add_each, Add, add);
+        impl_arith_op!($n, TupleSub, sub_each, Sub, sub);
+        impl_arith_op!($n, TupleMul, mul_each, Mul, mul);
+        impl_arith_op!($n, TupleDiv, div_each, Div, div);
+    };
+}
+
+for_each_tuple!(impl_arith);
+
+mod int_seal {
+    pub trait Sealed {}
+}
+
+/// Primitive integer types, which have checked, saturating and wrapping
+/// arithmetic.
+pub trait PrimInt: int_seal::Sealed + Copy {
+    #[doc(hidden)]
+    fn checked_add(self, other: Self) -> Option<Self>;
+    #[doc(hidden)]
+    fn checked_sub(self, other: Self) -> Option<Self>;
+    #[doc(hidden)]
+    fn checked_mul(self, other: Self) -> Option<Self>;
+    #[doc(hidden)]
+    fn checked_div(self, other: Self) -> Option<Self>;
+    #[doc(hidden)]
+    fn saturating_add(self, other: Self) -> Self;
+    #[doc(hidden)]
+    fn saturating_sub(self, other: Self) -> Self;
+    #[doc(hidden)]
+    fn saturating_mul(self, other: Self) -> Self;
+    #[doc(hidden)]
+    fn saturating_div(self, other: Self) -> Self;
+    #[doc(hidden)]
+    fn wrapping_add(self, other: Self) -> Self;
+    #[doc(hidden)]
+    fn wrapping_sub(self, other: Self) -> Self;
+    #[doc(hidden)]
+    fn wrapping_mul(self, other: Self) -> Self;
+    #[doc(hidden)]
+    fn wrapping_div(self, other: Self) -> Self;
+}
+
+macro_rules! impl_prim_int {
+    ($($ty:ident)*) => {
+        $(
+            impl int_seal::Sealed for $ty {}
+
+            impl PrimInt for $ty {
+                impl_prim_int!(@fn $ty; checked_add checked_sub checked_mul checked_div => Option<Self>);
+                impl_prim_int!(@fn $ty; saturating_add saturating_sub saturating_mul saturating_div => Self);
+                impl_prim_int!(@fn $ty; wrapping_add wrapping_sub wrapping_mul wrapping_div => Self);
+            }
+        )*
+    };
+    (@fn $ty:ident; $($method:ident)* => $ret:ty) => {
+        $(
+            fn $method(self, other: Self) -> $ret {
+                $ty::$method(self, other)
+            }
+        )*
+    };
+}
+
+impl_prim_int!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize);
+
+/// Elementwise checked arithmetic on tuples of integers.
+///
+/// Each operation returns `None` if any element overflows (or divides by
+/// zero).
+pub trait TupleChecked: seal::Sealed + Sized {
+    /// Checked elementwise addition.
+    /// ```rust
+    /// # use tuplestructops::TupleChecked;
+    /// assert_eq!((1u8, 2i32).checked_add_each((2, 3)), Some((3, 5)));
+    /// assert_eq!((255u8, 2i32).checked_add_each((1, 3)), None);
+    /// ```
+    fn checked_add_each(self, other: Self) -> Option<Self>;
+
+    /// Checked elementwise subtraction.
+    /// ```rust
+    /// # use tuplestructops::TupleChecked;
+    /// assert_eq!((0u8, 0i8).checked_sub_each((1, 1)), None);
+    /// ```
+    fn checked_sub_each(self, other: Self) -> Option<Self>;
+
+    /// Checked elementwise multiplication.
+    /// ```rust
+    /// # use tuplestructops::TupleChecked;
+    /// assert_eq!((16u8, 3u32).checked_mul_each((16, 3)), None);
+    /// ```
+    fn checked_mul_each(self, other: Self) -> Option<Self>;
+
+    /// Checked elementwise division.
+    /// ```rust
+    /// # use tuplestructops::TupleChecked;
+    /// assert_eq!((7u8, 9i64).checked_div_each((2, 3)), Some((3, 3)));
+    /// assert_eq!((7u8, 9i64).checked_div_each((2, 0)), None);
+    /// ```
+    fn checked_div_each(self, other: Self) -> Option<Self>;
+}
+
+/// Elementwise saturating arithmetic on tuples of integers.
+pub trait TupleSaturating: seal::Sealed {
+    /// Saturating elementwise addition.
+    /// ```rust
+    /// # use tuplestructops::TupleSaturating;
+    /// assert_eq!((250u8, 1i8).saturating_add_each((10, 1)), (255, 2));
+    /// ```
+    fn saturating_add_each(self, other: Self) -> Self;
+
+    /// Saturating elementwise subtraction.
+    /// ```rust
+    /// # use tuplestructops::TupleSaturating;
+    /// assert_eq!((1u8, -100i8).saturating_sub_each((2, 100)), (0, -128));
+    /// ```
+    fn saturating_sub_each(self, other: Self) -> Self;
+
+    /// Saturating elementwise multiplication.
+    /// ```rust
+    /// # use tuplestructops::TupleSaturating;
+    /// assert_eq!((16u8, 3u8).saturating_mul_each((16, 3)), (255, 9));
+    /// ```
+    fn saturating_mul_each(self, other: Self) -> Self;
+
+    /// Saturating elementwise division. This only saturates for `MIN / -1` of
+    /// signed integers, and panics when dividing by zero.
+    /// ```rust
+    /// # use tuplestructops::TupleSaturating;
+    /// assert_eq!((i8::MIN, 9u8).saturating_div_each((-1, 2)), (i8::MAX, 4));
+    /// ```
+    fn saturating_div_each(self, other: Self) -> Self;
+}
+
+/// Elementwise wrapping arithmetic on tuples of integers.
+pub trait TupleWrapping: seal::Sealed {
+    /// Wrapping elementwise addition.
+    /// ```rust
+    /// # use tuplestructops::TupleWrapping;
+    /// assert_eq!((250u8, 1i8).wrapping_add_each((10, 1)), (4, 2));
+    /// ```
+    fn wrapping_add_each(self, other: Self) -> Self;
+
+    /// Wrapping elementwise subtraction.
+    /// ```rust
+    /// # use tuplestructops::TupleWrapping;
+    /// assert_eq!((1u8, -128i8).wrapping_sub_each((2, 1)), (255, 127));
+    /// ```
+    fn wrapping_sub_each(self, other: Self) -> Self;
+
+    /// Wrapping elementwise multiplication.
+    /// ```rust
+    /// # use tuplestructops::TupleWrapping;
+    /// assert_eq!((16u8, 3u8).wrapping_mul_each((16, 3)), (0, 9));
+    /// ```
+    fn wrapping_mul_each(self, other: Self) -> Self;
+
+    /// Wrapping elementwise division. This only wraps for `MIN / -1` of signed
+    /// integers, and panics when dividing by zero.
+    /// ```rust
+    /// # use tuplestructops::TupleWrapping;
+    /// assert_eq!((i8::MIN, 9u8).wrapping_div_each((-1, 2)), (i8::MIN, 4));
+    /// ```
+    fn wrapping_div_each(self, other: Self) -> Self;
+}
+
+macro_rules! impl_int_arith {
+    ($n:literal) => {
+        seq!(J in 0..$n {
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<#(T~J: PrimInt,)*> TupleChecked for (#(T~J,)*) {
+                impl_int_arith!(@fn $n; checked_add_each checked_add => Option<Self>, ?);
+                impl_int_arith!(@fn $n; checked_sub_each checked_sub => Option<Self>, ?);
+                impl_int_arith!(@fn $n; checked_mul_each checked_mul => Option<Self>, ?);
+                impl_int_arith!(@fn $n; checked_div_each checked_div => Option<Self>, ?);
+            }
+
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<#(T~J: PrimInt,)*> TupleSaturating for (#(T~J,)*) {
+                impl_int_arith!(@fn $n; saturating_add_each saturating_add => Self);
+                impl_int_arith!(@fn $n; saturating_sub_each saturating_sub => Self);
+                impl_int_arith!(@fn $n; saturating_mul_each saturating_mul => Self);
+                impl_int_arith!(@fn $n; saturating_div_each saturating_div => Self);
+            }
+
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<#(T~J: PrimInt,)*> TupleWrapping for (#(T~J,)*) {
+                impl_int_arith!(@fn $n; wrapping_add_each wrapping_add => Self);
+                impl_int_arith!(@fn $n; wrapping_sub_each wrapping_sub => Self);
+                impl_int_arith!(@fn $n; wrapping_mul_each wrapping_mul => Self);
+                impl_int_arith!(@fn $n; wrapping_div_each wrapping_div => Self);
+            }
+        });
+    };
+    (@fn $n:literal; $method:ident $op:ident => Option<Self>, ?) => {
+        seq!(J in 0..$n {
+            #[allow(clippy::unused_unit, non_snake_case)]
+            fn $method(self, other: Self) -> Option<Self> {
+                let (#(L~J,)*) = self;
+                let (#(R~J,)*) = other;
+
+                Some((#(L~J.$op(R~J)?,)*))
+            }
+        });
+    };
+    (@fn $n:literal; $method:ident $op:ident => Self) => {
+        seq!(J in 0..$n {
+            #[allow(clippy::unused_unit, non_snake_case)]
+            fn $method(self, other: Self) -> Self {
+                let (#(L~J,)*) = self;
+                let (#(R~J,)*) = other;
+
+                (#(L~J.$op(R~J),)*)
+            }
+        });
+    };
+}
+
+for_each_tuple!(impl_int_arith);
+
+/// Elementwise minimum, maximum and clamping of tuples of the same shape.
+///
+/// These only require `PartialOrd`, so they work for floats. If a pair of
+/// elements is unordered (such as a NaN), the element from `self` is kept.
+pub trait TupleMinMax: seal::Sealed {
+    /// Elementwise minimum. Equal elements are taken from `self`.
+    /// ```rust
+    /// # use tuplestructops::TupleMinMax;
+    /// assert_eq!((1, 5.0, 'z').min_each((3, 2.0, 'a')), (1, 2.0, 'a'));
+    /// ```
+    fn min_each(self, other: Self) -> Self;
+
+    /// Elementwise maximum. Equal elements are taken from `self`.
+    /// ```rust
+    /// # use tuplestructops::TupleMinMax;
+    /// assert_eq!((1, 5.0, 'z').max_each((3, 2.0, 'a')), (3, 5.0, 'z'));
+    /// ```
+    fn max_each(self, other: Self) -> Self;
+
+    /// Clamp each element between the corresponding elements of `lo` and
+    /// `hi`. Unlike [`Ord::clamp`] this doesn't panic if `lo` is greater than
+    /// `hi`; `lo` takes precedence.
+    /// ```rust
+    /// # use tuplestructops::TupleMinMax;
+    /// let (lo, hi) = ((0.0, 0.0), (640.0, 480.0));
+    /// assert_eq!((-3.0, 200.0).clamp_each(lo, hi), (0.0, 200.0));
+    /// assert_eq!((700.0, 500.0).clamp_each(lo, hi), (640.0, 480.0));
+    /// ```
+    fn clamp_each(self, lo: Self, hi: Self) -> Self;
+}
+
+macro_rules! impl_min_max {
+    ($n:literal) => {
+        seq!(J in 0..$n {
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<#(T~J: PartialOrd,)*> TupleMinMax for (#(T~J,)*) {
+                #[allow(clippy::unused_unit, non_snake_case)]
+                fn min_each(self, other: Self) -> Self {
+                    let (#(L~J,)*) = self;
+                    let (#(R~J,)*) = other;
+
+                    (#(if R~J < L~J { R~J } else { L~J },)*)
+                }
+
+                #[allow(clippy::unused_unit, non_snake_case)]
+                fn max_each(self, other: Self) -> Self {
+                    let (#(L~J,)*) = self;
+                    let (#(R~J,)*) = other;
+
+                    (#(if R~J > L~J { R~J } else { L~J },)*)
+                }
+
+                #[allow(clippy::unused_unit, non_snake_case)]
+                fn clamp_each(self, lo: Self, hi: Self) -> Self {
+                    let (#(V~J,)*) = self;
+                    let (#(LO~J,)*) = lo;
+                    let (#(HI~J,)*) = hi;
+
+                    (#(
+                        if V~J < LO~J {
+                            LO~J
+                        } else if V~J > HI~J {
+                            if HI~J < LO~J { LO~J } else { HI~J }
+                        } else {
+                            V~J
+                        },
+                    )*)
+                }
+            }
+        });
+    };
+}
+
+for_each_tuple!(impl_min_max);
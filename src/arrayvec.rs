@@ -0,0 +1,53 @@
+//! Conversions between homogeneous tuples and [`ArrayVec`]s.
+//!
+//! A tuple converts into a full `ArrayVec` with the same capacity as its
+//! length, without allocating. Converting back accepts an `ArrayVec` of any
+//! capacity, and checks the length:
+//! ```rust
+//! # use arrayvec::ArrayVec;
+//! # use tuplestructops::arrayvec::TupleArrayVec;
+//! let v: ArrayVec<u8, 3> = (1, 2, 3).into_arrayvec();
+//! assert!(v.is_full());
+//!
+//! let mut v = ArrayVec::<u8, 8>::new();
+//! v.extend([4, 5]);
+//! assert_eq!(<(u8, u8)>::from_arrayvec(v), Ok((4, 5)));
+//! ```
+use ::arrayvec::ArrayVec;
+
+use super::homogeneous::LengthError;
+use super::*;
+
+/// Conversion between homogeneous tuples of length `N` and `ArrayVec`s.
+pub trait TupleArrayVec<T, const N: usize>: HomogeneousTuple<T> {
+    /// Move the elements into a full `ArrayVec` of capacity `N`.
+    fn into_arrayvec(self) -> ArrayVec<T, N>;
+
+    /// Move the elements of an `ArrayVec` of any capacity into a tuple,
+    /// failing if it's not the same length as the tuple.
+    fn from_arrayvec<const CAP: usize>(vec: ArrayVec<T, CAP>) -> Result<Self, LengthError>;
+}
+
+macro_rules! impl_arrayvec {
+    ($n:literal) => {
+        seq!(J in 0..$n {
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<T> TupleArrayVec<T, $n> for (#(T,)*) {
+                fn into_arrayvec(self) -> ArrayVec<T, $n> {
+                    ArrayVec::from(self.into_array())
+                }
+
+                #[allow(unused_mut, unused_variables, clippy::unused_unit)]
+                fn from_arrayvec<const CAP: usize>(vec: ArrayVec<T, CAP>) -> Result<Self, LengthError> {
+                    if vec.len() != $n {
+                        return Err(LengthError::new($n, vec.len()));
+                    }
+                    let mut elems = vec.into_iter();
+                    Ok((#(elems.next().unwrap(),)*))
+                }
+            }
+        });
+    };
+}
+
+for_each_tuple!(impl_arrayvec);
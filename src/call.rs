@@ -0,0 +1,237 @@
+use super::*;
+
+/// Apply a tuple of functions elementwise to a tuple of arguments.
+pub trait TupleApplyEach<Args>: seal::Sealed {
+    /// Tuple of the functions' results.
+    type Output;
+
+    /// Call each function in `self` with the corresponding element of `args`.
+    /// ```rust
+    /// # use tuplestructops::TupleApplyEach;
+    /// let out = (|x: i32| x + 1, |s: &str| s.len(), char::is_alphabetic)
+    ///     .apply_each((1, "abc", 'x'));
+    /// assert_eq!(out, (2, 3, true));
+    /// ```
+    /// A tuple of functions by reference calls them as `Fn`, so they can be
+    /// reused.
+    /// ```rust
+    /// # use tuplestructops::TupleApplyEach;
+    /// let fns = (|x: i32| x * 2, |x: i32| x * 3);
+    /// assert_eq!((&fns).apply_each((1, 2)), (2, 6));
+    /// assert_eq!((&fns).apply_each((3, 4)), (6, 12));
+    /// ```
+    fn apply_each(self, args: Args) -> Self::Output;
+}
+
+macro_rules! impl_apply_each {
+    ($n:literal) => {
+        seq!(J in 0..$n {
+            // Apply by value
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<#(F~J, A~J, R~J,)*> TupleApplyEach<(#(A~J,)*)> for (#(F~J,)*)
+            where
+                #(F~J: FnOnce(A~J) -> R~J,)*
+            {
+                type Output = (#(R~J,)*);
+
+                #[allow(clippy::unused_unit, non_snake_case)]
+                fn apply_each(self, args: (#(A~J,)*)) -> Self::Output {
+                    let (#(F~J,)*) = self;
+                    let (#(A~J,)*) = args;
+
+                    (#(F~J(A~J),)*)
+                }
+            }
+
+            // Apply by reference
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<'a, #(F~J, A~J, R~J,)*> TupleApplyEach<(#(A~J,)*)> for &'a (#(F~J,)*)
+            where
+                #(F~J: Fn(A~J) -> R~J,)*
+            {
+                type Output = (#(R~J,)*);
+
+                #[allow(clippy::unused_unit, non_snake_case)]
+                fn apply_each(self, args: (#(A~J,)*)) -> Self::Output {
+                    let (#(F~J,)*) = self;
+                    let (#(A~J,)*) = args;
+
+                    (#(F~J(A~J),)*)
+                }
+            }
+        });
+    };
+}
+
+for_each_tuple!(impl_apply_each);
+
+/// Run a tuple of functions as a pipeline, feeding each function's output into
+/// the next.
+pub trait TuplePipe<A>: seal::Sealed {
+    /// Output of the final stage.
+    type Output;
+
+    /// Pass `input` to the first function, its result to the second, and so
+    /// on, returning the result of the last. The stages' input and output types
+    /// must line up, which is checked at compile time.
+    /// ```rust
+    /// # use tuplestructops::TuplePipe;
+    /// let out = (|s: &str| s.len(), |n: usize| n * 2, |n: usize| format!("{n}!"))
+    ///     .pipe("abc");
+    /// assert_eq!(out, "6!");
+    /// ```
+    /// An empty pipeline returns its input unchanged.
+    /// ```rust
+    /// # use tuplestructops::TuplePipe;
+    /// assert_eq!(().pipe(1), 1);
+    /// ```
+    fn pipe(self, input: A) -> Self::Output;
+}
+
+macro_rules! impl_pipe {
+    (0) => {
+        #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+        impl<A> TuplePipe<A> for () {
+            type Output = A;
+
+            fn pipe(self, input: A) -> A {
+                input
+            }
+        }
+
+        #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+        impl<'a, A> TuplePipe<A> for &'a () {
+            type Output = A;
+
+            fn pipe(self, input: A) -> A {
+                input
+            }
+        }
+    };
+    ($n:literal) => {
+        seq!(J in 1..$n {
+            // Pipe by value: apply the first stage, then pipe the rest
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<A, R, F0, #(F~J,)*> TuplePipe<A> for (F0, #(F~J,)*)
+            where
+                F0: FnOnce(A) -> R,
+                (#(F~J,)*): TuplePipe<R>,
+            {
+                type Output = <(#(F~J,)*) as TuplePipe<R>>::Output;
+
+                #[allow(non_snake_case)]
+                fn pipe(self, input: A) -> Self::Output {
+                    let (F0, #(F~J,)*) = self;
+
+                    (#(F~J,)*).pipe(F0(input))
+                }
+            }
+
+            // Pipe by reference, calling each stage as `Fn`
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<'a, A, F0, #(F~J,)*> TuplePipe<A> for &'a (F0, #(F~J,)*)
+            where
+                (&'a F0, #(&'a F~J,)*): TuplePipe<A>,
+            {
+                type Output = <(&'a F0, #(&'a F~J,)*) as TuplePipe<A>>::Output;
+
+                #[allow(non_snake_case)]
+                fn pipe(self, input: A) -> Self::Output {
+                    let (F0, #(F~J,)*) = self;
+
+                    (F0, #(F~J,)*).pipe(input)
+                }
+            }
+        });
+    };
+}
+
+for_each_tuple!(impl_pipe);
+
+/// Call a function with the elements of a tuple as its arguments.
+pub trait TupleCall<F>: seal::Sealed {
+    /// Return type of the function.
+    type Output;
+
+    /// Call `f` with each element of the tuple as a separate argument.
+    /// ```rust
+    /// # use tuplestructops::{TupleCall, TupleJoin};
+    /// fn describe(name: &str, age: u32, admin: bool) -> String {
+    ///     format!("{name} ({age}){}", if admin { " [admin]" } else { "" })
+    /// }
+    ///
+    /// let out = ("alice",).join((30, true)).apply(describe);
+    /// assert_eq!(out, "alice (30) [admin]");
+    /// ```
+    /// By reference, the function is passed references to the elements.
+    /// ```rust
+    /// # use tuplestructops::TupleCall;
+    /// let args = (String::from("abc"), 2);
+    /// assert_eq!((&args).apply(|s: &String, n: &usize| s.len() * n), 6);
+    /// ```
+    fn apply(self, f: F) -> Self::Output;
+}
+
+macro_rules! impl_call {
+    ($n:literal) => {
+        seq!(J in 0..$n {
+            // Call by value
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<F, R, #(T~J,)*> TupleCall<F> for (#(T~J,)*)
+            where
+                F: FnOnce(#(T~J,)*) -> R,
+            {
+                type Output = R;
+
+                #[allow(non_snake_case)]
+                fn apply(self, f: F) -> R {
+                    let (#(T~J,)*) = self;
+
+                    f(#(T~J,)*)
+                }
+            }
+
+            // Call by reference
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<'a, F, R, #(T~J,)*> TupleCall<F> for &'a (#(T~J,)*)
+            where
+                F: FnOnce(#(&'a T~J,)*) -> R,
+            {
+                type Output = R;
+
+                #[allow(non_snake_case)]
+                fn apply(self, f: F) -> R {
+                    let (#(T~J,)*) = self;
+
+                    f(#(T~J,)*)
+                }
+            }
+        });
+    };
+}
+
+for_each_tuple!(impl_call);
+
+/// Partially apply a function by binding a prefix of its arguments.
+///
+/// The returned closure takes the remaining arguments as a tuple, joins them
+/// onto a clone of `prefix` with [`TupleJoin`], and calls `f` with the result.
+/// ```rust
+/// # use tuplestructops::bind_prefix;
+/// fn greet(greeting: &str, name: &str, punct: char) -> String {
+///     format!("{greeting}, {name}{punct}")
+/// }
+///
+/// let hello = bind_prefix(greet, ("Hello",));
+/// assert_eq!(hello(("world", '!')), "Hello, world!");
+///
+/// let hello_bob = bind_prefix(greet, ("Hello", "Bob"));
+/// assert_eq!(hello_bob(('?',)), "Hello, Bob?");
+/// ```
+pub fn bind_prefix<F, P, Rest, R>(f: F, prefix: P) -> impl Fn(Rest) -> R
+where
+    P: TupleJoin<Rest> + Clone,
+    for<'f> TupleJoinOutput<P, Rest>: TupleCall<&'f F, Output = R>,
+{
+    move |rest| prefix.clone().join(rest).apply(&f)
+}
@@ -0,0 +1,92 @@
+use super::*;
+
+/// Convert a flat tuple into a nested cons list of pairs.
+///
+/// `(A, B, C)` becomes `(A, (B, (C, ())))`, the encoding used by
+/// `tuple_list`-style crates. This allows an algorithm to be written once as a
+/// pair of recursive trait impls, for `()` and `(Head, Tail)`, and then applied
+/// to flat tuples of any size.
+/// ```rust
+/// # use tuplestructops::{ConsIntoTuple, TupleIntoCons};
+/// trait CountZeros {
+///     fn count_zeros(&self) -> usize;
+/// }
+///
+/// impl CountZeros for () {
+///     fn count_zeros(&self) -> usize {
+///         0
+///     }
+/// }
+///
+/// impl<Head: Into<f64> + Copy, Tail: CountZeros> CountZeros for (Head, Tail) {
+///     fn count_zeros(&self) -> usize {
+///         (self.0.into() == 0.0) as usize + self.1.count_zeros()
+///     }
+/// }
+///
+/// let cons = (0u8, 1.5f32, 0i32).into_cons();
+/// assert_eq!(cons, (0, (1.5, (0, ()))));
+/// assert_eq!(cons.count_zeros(), 2);
+/// assert_eq!(cons.into_tuple(), (0, 1.5, 0));
+/// ```
+pub trait TupleIntoCons: seal::Sealed {
+    /// Nested cons list with the same elements.
+    type Cons: ConsIntoTuple<Tuple = Self>;
+
+    /// Move each element into a nested cons list.
+    fn into_cons(self) -> Self::Cons;
+}
+
+/// Convert a nested cons list of pairs, such as `(A, (B, (C, ())))`, into a
+/// flat tuple. This is the inverse of [`TupleIntoCons`].
+pub trait ConsIntoTuple: seal::Sealed {
+    /// Flat tuple with the same elements.
+    type Tuple: TupleIntoCons<Cons = Self>;
+
+    /// Move each element into a flat tuple.
+    fn into_tuple(self) -> Self::Tuple;
+}
+
+/// Nested cons list type for the flat tuple `T`.
+/// ```rust
+/// # use tuplestructops::Cons;
+/// let _: Cons<(u8, char)> = (1, ('a', ()));
+/// ```
+pub type Cons<T> = <T as TupleIntoCons>::Cons;
+
+// Expand a list of tokens, each followed by a comma, into a nested cons list.
+// This works in type, expression and pattern position.
+macro_rules! cons {
+    () => { () };
+    ($head:tt, $($tail:tt,)*) => { ($head, cons!($($tail,)*)) };
+}
+
+macro_rules! impl_cons {
+    ($n:literal) => {
+        seq!(J in 0..$n {
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<#(T~J,)*> TupleIntoCons for (#(T~J,)*) {
+                type Cons = cons!(#(T~J,)*);
+
+                #[allow(non_snake_case, clippy::unused_unit)]
+                fn into_cons(self) -> Self::Cons {
+                    let (#(T~J,)*) = self;
+                    cons!(#(T~J,)*)
+                }
+            }
+
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<#(T~J,)*> ConsIntoTuple for cons!(#(T~J,)*) {
+                type Tuple = (#(T~J,)*);
+
+                #[allow(non_snake_case, clippy::unused_unit)]
+                fn into_tuple(self) -> Self::Tuple {
+                    let cons!(#(T~J,)*) = self;
+                    (#(T~J,)*)
+                }
+            }
+        });
+    };
+}
+
+for_each_tuple!(impl_cons);
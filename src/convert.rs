@@ -0,0 +1,386 @@
+//! Elementwise type conversions between tuples.
+//!
+//! The traits are re-exported at the crate root. This module also contains the
+//! per-arity error types used by [`TupleTryConvert`], [`ParseTupleError`]
+//! for [`TupleFromStr`], and [`FromAnyVecError`] for [`TupleAnyVec`].
+use std::any::{type_name, Any};
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+use super::*;
+
+/// Elementwise conversion between tuples of the same length.
+///
+/// This is implemented for `(A, B, ...)` to `(X, Y, ...)` whenever `A:
+/// Into<X>`, `B: Into<Y>` and so on.
+pub trait TupleConvert<T>: seal::Sealed {
+    /// Convert each element of `self` into the corresponding element of `T`.
+    /// The target type is generally inferred from the calling context.
+    /// ```rust
+    /// # use tuplestructops::TupleConvert;
+    /// let out: (String, i64, f64) = ("hello", 1i32, 2.5f32).convert();
+    /// assert_eq!(out, ("hello".to_string(), 1, 2.5));
+    /// ```
+    fn convert(self) -> T;
+}
+
+macro_rules! impl_convert {
+    ($n:literal) => {
+        seq!(J in 0..$n {
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<#(T~J, U~J,)*> TupleConvert<(#(U~J,)*)> for (#(T~J,)*)
+            where
+                #(T~J: Into<U~J>,)*
+            {
+                #[allow(clippy::unused_unit, non_snake_case)]
+                fn convert(self) -> (#(U~J,)*) {
+                    let (#(T~J,)*) = self;
+
+                    (#(T~J.into(),)*)
+                }
+            }
+        });
+    };
+}
+
+for_each_tuple!(impl_convert);
+
+/// Fallible elementwise conversion between tuples of the same length.
+///
+/// This is implemented for `(A, B, ...)` to `(X, Y, ...)` whenever `A:
+/// TryInto<X>`, `B: TryInto<Y>` and so on.
+pub trait TupleTryConvert<T>: seal::Sealed {
+    /// Error type, identifying which element failed to convert. This is one of
+    /// the [`TryConvertError0`] ... `TryConvertErrorN` enums, with a variant
+    /// per position.
+    type Error;
+
+    /// Convert each element of `self` into the corresponding element of `T`,
+    /// stopping at the first failure.
+    /// ```rust
+    /// # use tuplestructops::TupleTryConvert;
+    /// # use tuplestructops::convert::TryConvertError3;
+    /// let out: Result<(u8, u8, u8), _> = (1i32, 2i64, 3u32).try_convert();
+    /// assert_eq!(out, Ok((1, 2, 3)));
+    ///
+    /// let out: Result<(u8, u8, u8), _> = (1i32, 300i64, -1i32).try_convert();
+    /// assert!(matches!(out, Err(TryConvertError3::Elem1(_))));
+    /// assert_eq!(out.unwrap_err().index(), 1);
+    /// ```
+    fn try_convert(self) -> Result<T, Self::Error>;
+}
+
+macro_rules! impl_try_convert {
+    ($n:literal) => {
+        seq!(N in $n..=$n { #( seq!(J in 0..N {
+            /// Error from [`TupleTryConvert`], identifying which element
+            /// failed to convert.
+            #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+            pub enum TryConvertError~N<#(E~J,)*> {
+                #(
+                    #[doc = concat!("Element ", stringify!(J), " failed to convert")]
+                    Elem~J(E~J),
+                )*
+            }
+
+            impl<#(E~J,)*> TryConvertError~N<#(E~J,)*> {
+                /// Index of the element which failed to convert.
+                pub fn index(&self) -> usize {
+                    match *self {
+                        #(Self::Elem~J(_) => J,)*
+                    }
+                }
+            }
+
+            impl<#(E~J: fmt::Display,)*> fmt::Display for TryConvertError~N<#(E~J,)*> {
+                #[allow(unused_variables)]
+                fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+                    match *self {
+                        #(Self::Elem~J(ref err) => write!(fmt, "element {}: {}", J, err),)*
+                    }
+                }
+            }
+
+            impl<#(E~J: Error,)*> Error for TryConvertError~N<#(E~J,)*> {}
+
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<#(T~J, U~J,)*> TupleTryConvert<(#(U~J,)*)> for (#(T~J,)*)
+            where
+                #(T~J: TryInto<U~J>,)*
+            {
+                type Error = TryConvertError~N<#(T~J::Error,)*>;
+
+                #[allow(clippy::unused_unit, non_snake_case)]
+                fn try_convert(self) -> Result<(#(U~J,)*), Self::Error> {
+                    let (#(T~J,)*) = self;
+
+                    Ok((#(T~J.try_into().map_err(TryConvertError~N::Elem~J)?,)*))
+                }
+            }
+        }); )* });
+    };
+}
+
+for_each_tuple!(impl_try_convert);
+
+/// Error from [`TupleFromStr::parse_tuple`], or from parsing a csv record with
+/// the `csv` feature, identifying the field at fault.
+#[derive(Debug)]
+pub struct ParseTupleError {
+    index: usize,
+    kind: ParseTupleErrorKind,
+}
+
+/// The reason a [`ParseTupleError`] occurred.
+#[derive(Debug)]
+pub enum ParseTupleErrorKind {
+    /// The input had the wrong number of fields.
+    Count {
+        /// Number of elements in the tuple.
+        expected: usize,
+        /// Number of fields in the input.
+        found: usize,
+    },
+    /// A field failed to parse with its element's `FromStr`.
+    Field(Box<dyn Error + Send + Sync>),
+}
+
+impl ParseTupleError {
+    pub(crate) fn count(expected: usize, found: usize) -> Self {
+        ParseTupleError {
+            index: expected.min(found),
+            kind: ParseTupleErrorKind::Count { expected, found },
+        }
+    }
+
+    pub(crate) fn field(index: usize, err: impl Into<Box<dyn Error + Send + Sync>>) -> Self {
+        ParseTupleError {
+            index,
+            kind: ParseTupleErrorKind::Field(err.into()),
+        }
+    }
+
+    /// Index of the field which failed to parse. For a field count mismatch,
+    /// this is the index of the first missing or extra field.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// The reason for the error.
+    pub fn kind(&self) -> &ParseTupleErrorKind {
+        &self.kind
+    }
+}
+
+impl fmt::Display for ParseTupleError {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match self.kind {
+            ParseTupleErrorKind::Count { expected, found } => {
+                write!(fmt, "expected {} fields, found {}", expected, found)
+            }
+            ParseTupleErrorKind::Field(ref err) => write!(fmt, "field {}: {}", self.index, err),
+        }
+    }
+}
+
+impl Error for ParseTupleError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self.kind {
+            ParseTupleErrorKind::Count { .. } => None,
+            ParseTupleErrorKind::Field(ref err) => Some(&**err),
+        }
+    }
+}
+
+/// Parse a delimited string into a tuple, using each element's `FromStr`.
+pub trait TupleFromStr: seal::Sealed + Sized {
+    /// Split `s` on `delim` and parse each field into the corresponding
+    /// element. The input must have exactly as many fields as the tuple has
+    /// elements. Fields are not trimmed, and the empty string is a single
+    /// empty field, except for the unit tuple which only parses from `""`.
+    /// ```rust
+    /// # use tuplestructops::TupleFromStr;
+    /// let t = <(u32, f64, bool)>::parse_tuple("1;2.5;true", ';').unwrap();
+    /// assert_eq!(t, (1, 2.5, true));
+    ///
+    /// let err = <(u32, f64, bool)>::parse_tuple("1;x;true", ';').unwrap_err();
+    /// assert_eq!(err.index(), 1);
+    /// ```
+    fn parse_tuple(s: &str, delim: char) -> Result<Self, ParseTupleError>;
+}
+
+/// Parse a delimited string into a tuple of type `T`. See
+/// [`TupleFromStr::parse_tuple`].
+/// ```rust
+/// # use tuplestructops::parse_tuple;
+/// let (name, port) = parse_tuple::<(String, u16)>("localhost:8080", ':').unwrap();
+/// assert_eq!((name.as_str(), port), ("localhost", 8080));
+///
+/// let err = parse_tuple::<(String, u16)>("localhost", ':').unwrap_err();
+/// assert_eq!(err.to_string(), "expected 2 fields, found 1");
+/// ```
+pub fn parse_tuple<T: TupleFromStr>(s: &str, delim: char) -> Result<T, ParseTupleError> {
+    T::parse_tuple(s, delim)
+}
+
+macro_rules! impl_from_str {
+    (0) => {
+        #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+        impl TupleFromStr for () {
+            fn parse_tuple(s: &str, delim: char) -> Result<Self, ParseTupleError> {
+                if s.is_empty() {
+                    Ok(())
+                } else {
+                    Err(ParseTupleError::count(0, s.split(delim).count()))
+                }
+            }
+        }
+    };
+    ($n:literal) => {
+        seq!(J in 0..$n {
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<#(T~J,)*> TupleFromStr for (#(T~J,)*)
+            where
+                #(T~J: FromStr, T~J::Err: Into<Box<dyn Error + Send + Sync>>,)*
+            {
+                fn parse_tuple(s: &str, delim: char) -> Result<Self, ParseTupleError> {
+                    let mut fields = s.split(delim);
+                    let res = (#(
+                        fields
+                            .next()
+                            .ok_or_else(|| ParseTupleError::count($n, J))?
+                            .parse::<T~J>()
+                            .map_err(|err| ParseTupleError::field(J, err))?,
+                    )*);
+                    let extra = fields.count();
+                    if extra > 0 {
+                        return Err(ParseTupleError::count($n, $n + extra));
+                    }
+                    Ok(res)
+                }
+            }
+        });
+    };
+}
+
+for_each_tuple!(impl_from_str);
+
+/// Error from [`TupleAnyVec::from_any_vec`], which returns the vector
+/// unchanged.
+#[derive(Debug)]
+pub struct FromAnyVecError {
+    kind: FromAnyVecErrorKind,
+    vec: Vec<Box<dyn Any>>,
+}
+
+/// The reason a [`FromAnyVecError`] occurred.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FromAnyVecErrorKind {
+    /// The vector had the wrong number of elements.
+    Count {
+        /// Number of elements in the tuple.
+        expected: usize,
+        /// Number of elements in the vector.
+        found: usize,
+    },
+    /// An element was not of the tuple's type at that position.
+    Type {
+        /// Index of the mismatched element.
+        index: usize,
+        /// Name of the expected type, from [`std::any::type_name`].
+        expected: &'static str,
+    },
+}
+
+impl FromAnyVecError {
+    /// The reason for the error.
+    pub fn kind(&self) -> FromAnyVecErrorKind {
+        self.kind
+    }
+
+    /// Recover the vector passed to `from_any_vec`.
+    pub fn into_vec(self) -> Vec<Box<dyn Any>> {
+        self.vec
+    }
+}
+
+impl fmt::Display for FromAnyVecError {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match self.kind {
+            FromAnyVecErrorKind::Count { expected, found } => {
+                write!(fmt, "expected {} elements, found {}", expected, found)
+            }
+            FromAnyVecErrorKind::Type { index, expected } => {
+                write!(fmt, "element {}: expected {}", index, expected)
+            }
+        }
+    }
+}
+
+impl Error for FromAnyVecError {}
+
+/// Conversion between tuples and vectors of type-erased elements.
+pub trait TupleAnyVec: seal::Sealed + Sized {
+    /// Box each element as a `dyn Any`, in order.
+    /// ```rust
+    /// # use tuplestructops::TupleAnyVec;
+    /// let v = (1u8, "two", 3.0f64).into_any_vec();
+    /// assert_eq!(v.len(), 3);
+    /// assert_eq!(v[1].downcast_ref::<&str>(), Some(&"two"));
+    /// ```
+    fn into_any_vec(self) -> Vec<Box<dyn Any>>;
+
+    /// Downcast each element of `vec` into the corresponding element of the
+    /// tuple. The vector must have exactly as many elements as the tuple, each
+    /// of the exact type at its position. All elements are checked before any
+    /// are moved, so on failure the error holds the original vector.
+    /// ```rust
+    /// # use std::any::Any;
+    /// # use tuplestructops::TupleAnyVec;
+    /// let v: Vec<Box<dyn Any>> = vec![Box::new(1u8), Box::new("two")];
+    /// assert_eq!(<(u8, &str)>::from_any_vec(v).unwrap(), (1, "two"));
+    ///
+    /// let v: Vec<Box<dyn Any>> = vec![Box::new(1u8), Box::new(2u8)];
+    /// let err = <(u8, &str)>::from_any_vec(v).unwrap_err();
+    /// assert_eq!(err.to_string(), "element 1: expected &str");
+    /// assert_eq!(err.into_vec().len(), 2);
+    /// ```
+    fn from_any_vec(vec: Vec<Box<dyn Any>>) -> Result<Self, FromAnyVecError>;
+}
+
+macro_rules! impl_any_vec {
+    ($n:literal) => {
+        seq!(J in 0..$n {
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<#(T~J: Any,)*> TupleAnyVec for (#(T~J,)*) {
+                fn into_any_vec(self) -> Vec<Box<dyn Any>> {
+                    vec![#(Box::new(self.J) as Box<dyn Any>,)*]
+                }
+
+                #[allow(unused_mut, unused_variables)]
+                fn from_any_vec(vec: Vec<Box<dyn Any>>) -> Result<Self, FromAnyVecError> {
+                    let kind = if vec.len() != $n {
+                        Some(FromAnyVecErrorKind::Count { expected: $n, found: vec.len() })
+                    } else {
+                        None #(.or_else(|| {
+                            (!vec[J].is::<T~J>()).then(|| FromAnyVecErrorKind::Type {
+                                index: J,
+                                expected: type_name::<T~J>(),
+                            })
+                        }))*
+                    };
+                    if let Some(kind) = kind {
+                        return Err(FromAnyVecError { kind, vec });
+                    }
+
+                    // Every element has been checked, so the downcasts can't fail.
+                    let mut elems = vec.into_iter();
+                    Ok((#(*elems.next().unwrap().downcast::<T~J>().unwrap(),)*))
+                }
+            }
+        });
+    };
+}
+
+for_each_tuple!(impl_any_vec);
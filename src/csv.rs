@@ -0,0 +1,77 @@
+//! Conversions between tuples and [`csv`](::csv) records.
+//!
+//! [`TupleFromRecord`] parses each field of a `StringRecord` into the
+//! corresponding element with `FromStr`, and [`TupleToRecord`] formats each
+//! element into a field with `Display`. Unlike csv's serde support, this
+//! works for tuples of any size this crate supports:
+//! ```rust
+//! # use tuplestructops::csv::{TupleFromRecord, TupleToRecord};
+//! let data = "id,name,score\n1,ada,9.5\n2,bob,7\n";
+//! let mut reader = csv::Reader::from_reader(data.as_bytes());
+//! let mut writer = csv::Writer::from_writer(vec![]);
+//! for record in reader.records() {
+//!     let (id, name, score) = <(u32, String, f64)>::from_record(&record.unwrap()).unwrap();
+//!     writer.write_record(&(name, id, score * 2.0).to_record()).unwrap();
+//! }
+//! let out = String::from_utf8(writer.into_inner().unwrap()).unwrap();
+//! assert_eq!(out, "ada,1,19\nbob,2,14\n");
+//! ```
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use ::csv::StringRecord;
+
+use super::convert::ParseTupleError;
+use super::*;
+
+/// Parse a CSV record into a tuple.
+pub trait TupleFromRecord: seal::Sealed + Sized {
+    /// Parse each field of `record` into the corresponding element, with its
+    /// `FromStr` impl. The record must have exactly as many fields as the
+    /// tuple has elements. Fields are parsed as they are, so use the reader's
+    /// `trim` option to ignore surrounding whitespace.
+    fn from_record(record: &StringRecord) -> Result<Self, ParseTupleError>;
+}
+
+/// Format a tuple as a CSV record.
+pub trait TupleToRecord: seal::Sealed {
+    /// Format each element into a field, with its `Display` impl.
+    fn to_record(&self) -> StringRecord;
+}
+
+macro_rules! impl_csv {
+    ($n:literal) => {
+        seq!(J in 0..$n {
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<#(T~J,)*> TupleFromRecord for (#(T~J,)*)
+            where
+                #(T~J: FromStr, T~J::Err: Into<Box<dyn Error + Send + Sync>>,)*
+            {
+                #[allow(clippy::unused_unit)]
+                fn from_record(record: &StringRecord) -> Result<Self, ParseTupleError> {
+                    if record.len() != $n {
+                        return Err(ParseTupleError::count($n, record.len()));
+                    }
+                    Ok((#(
+                        record[J]
+                            .parse::<T~J>()
+                            .map_err(|err| ParseTupleError::field(J, err))?,
+                    )*))
+                }
+            }
+
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<#(T~J: Display,)*> TupleToRecord for (#(T~J,)*) {
+                #[allow(unused_mut)]
+                fn to_record(&self) -> StringRecord {
+                    let mut record = StringRecord::with_capacity(0, $n);
+                    #(record.push_field(&self.J.to_string());)*
+                    record
+                }
+            }
+        });
+    };
+}
+
+for_each_tuple!(impl_csv);
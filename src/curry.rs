@@ -0,0 +1,127 @@
+//! Currying and uncurrying of functions.
+//!
+//! [`curry2`] ... `curryN` convert a function of N arguments into nested
+//! single-argument closures, and [`uncurry2`] ... `uncurryN` convert them back.
+//! ```rust
+//! use tuplestructops::curry::{curry3, uncurry3};
+//!
+//! let add = curry3(|a: i32, b: i32, c: i32| a + b + c);
+//! let add1 = add(1);
+//! assert_eq!(add1(2)(3), 6);
+//! assert_eq!(add1(10)(20), 31);
+//!
+//! let add = uncurry3(add);
+//! assert_eq!(add(1, 2, 3), 6);
+//! ```
+use std::rc::Rc;
+
+use super::*;
+
+/// Nested single-argument functions, taking their arguments as a tuple.
+///
+/// This is implemented for any `Fn(A) -> G` where `G` is itself `Curried` for
+/// the remaining arguments, and is used by the `uncurryN` functions.
+pub trait Curried<Args> {
+    /// Result of applying all the arguments.
+    type Output;
+
+    /// Apply each argument in turn to the nested functions.
+    /// ```rust
+    /// # use tuplestructops::curry::Curried;
+    /// let f = |a: i32| move |b: i32| a * b;
+    /// assert_eq!(f.call_curried((6, 7)), 42);
+    /// ```
+    fn call_curried(&self, args: Args) -> Self::Output;
+}
+
+macro_rules! impl_curry {
+    (0) => {};
+    (1) => {
+        #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+        impl<G, R, T0> Curried<(T0,)> for G
+        where
+            G: Fn(T0) -> R,
+        {
+            type Output = R;
+
+            fn call_curried(&self, args: (T0,)) -> R {
+                self(args.0)
+            }
+        }
+    };
+    ($n:literal) => {
+        seq!(N in $n..=$n { #( seq!(J in 1..N {
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<G, H, T0, #(T~J,)*> Curried<(T0, #(T~J,)*)> for G
+            where
+                G: Fn(T0) -> H,
+                H: Curried<(#(T~J,)*)>,
+            {
+                type Output = H::Output;
+
+                #[allow(non_snake_case)]
+                fn call_curried(&self, args: (T0, #(T~J,)*)) -> Self::Output {
+                    let (T0, #(T~J,)*) = args;
+
+                    self(T0).call_curried((#(T~J,)*))
+                }
+            }
+
+            impl_curry!(@fn curry~N uncurry~N [] [T0 #(T~J)*] [a0: T0 #(a~J: T~J)*] T0 #(T~J)*);
+        }); )* });
+    };
+
+    // Find all but the last argument type, since those need to be `Clone`.
+    (@fn $curry:ident $uncurry:ident [$($clone:ident)*] $all:tt $args:tt $last:ident) => {
+        impl_curry!(@fn $curry $uncurry [$($clone)*] $all $args);
+    };
+    (@fn $curry:ident $uncurry:ident [$($clone:ident)*] $all:tt $args:tt $ty:ident $($rest:ident)+) => {
+        impl_curry!(@fn $curry $uncurry [$($clone)* $ty] $all $args $($rest)+);
+    };
+    (@fn $curry:ident $uncurry:ident [$($clone:ident)*] [$($ty:ident)*] [$($arg:ident: $argty:ident)*]) => {
+        /// Convert a function into nested single-argument closures.
+        ///
+        /// The inner closures are boxed, and clone the arguments they have
+        /// captured so far so they can be called more than once.
+        pub fn $curry<'a, F, R, $($ty,)*>(f: F) -> impl_curry!(@ty 'a R; $($ty)*)
+        where
+            F: Fn($($ty),*) -> R + 'a,
+            $($clone: Clone + 'a,)*
+        {
+            let f = Rc::new(f);
+
+            impl_curry!(@closure f; []; $($arg: $argty)*)
+        }
+
+        /// Convert nested single-argument closures back into a function of
+        /// several arguments.
+        pub fn $uncurry<G, $($ty,)*>(g: G) -> impl Fn($($ty),*) -> G::Output
+        where
+            G: Curried<($($ty,)*)>,
+        {
+            move |$($arg),*| g.call_curried(($($arg,)*))
+        }
+    };
+
+    (@ty $lt:lifetime $ret:ident; $ty:ident $($rest:ident)+) => {
+        impl Fn($ty) -> impl_curry!(@boxty $lt $ret; $($rest)+)
+    };
+    (@boxty $lt:lifetime $ret:ident; $ty:ident) => { Box<dyn Fn($ty) -> $ret + $lt> };
+    (@boxty $lt:lifetime $ret:ident; $ty:ident $($rest:ident)+) => {
+        Box<dyn Fn($ty) -> impl_curry!(@boxty $lt $ret; $($rest)+) + $lt>
+    };
+
+    (@closure $f:ident; [$($bound:ident)*]; $arg:ident: $ty:ident) => {
+        move |$arg: $ty| (*$f)($($bound.clone(),)* $arg)
+    };
+    (@closure $f:ident; [$($bound:ident)*]; $arg:ident: $ty:ident $($rest:tt)+) => {
+        move |$arg: $ty| {
+            let $f = $f.clone();
+            $(let $bound = $bound.clone();)*
+
+            Box::new(impl_curry!(@closure $f; [$($bound)* $arg]; $($rest)+))
+        }
+    };
+}
+
+for_each_tuple!(impl_curry);
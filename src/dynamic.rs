@@ -0,0 +1,293 @@
+//! Runtime access to tuples.
+//!
+//! [`DynTuple`], [`TupleIdxDyn`], [`TupleForEachDyn`] and [`TupleAsDyn`] are
+//! re-exported at the crate root. This module also contains the per-arity
+//! element enums returned by [`TupleIdxDyn::idx_dyn`].
+use std::any::Any;
+
+use crate::convert::FromAnyVecError;
+
+use super::*;
+
+/// A tuple whose length and element types are only known at runtime.
+///
+/// This is an ordered sequence of type-erased elements, which can be built up
+/// one element at a time and then converted into a statically typed tuple
+/// with [`try_into_tuple`](Self::try_into_tuple).
+/// ```rust
+/// # use tuplestructops::DynTuple;
+/// let mut t = DynTuple::new();
+/// t.push(1u8);
+/// t.push("two");
+/// assert_eq!(t.len(), 2);
+/// assert_eq!(t.get::<&str>(1), Some(&"two"));
+/// assert_eq!(t.get::<u16>(0), None);
+///
+/// let (a, b) = t.try_into_tuple::<(u8, &str)>().unwrap();
+/// assert_eq!((a, b), (1, "two"));
+/// ```
+#[derive(Debug, Default)]
+pub struct DynTuple {
+    elems: Vec<Box<dyn Any>>,
+}
+
+impl DynTuple {
+    /// Make an empty `DynTuple`.
+    pub fn new() -> Self {
+        DynTuple { elems: Vec::new() }
+    }
+
+    /// Make a `DynTuple` from the elements of a statically typed tuple.
+    /// ```rust
+    /// # use tuplestructops::DynTuple;
+    /// let t = DynTuple::from_tuple((1u8, 'b'));
+    /// assert_eq!(t.get::<char>(1), Some(&'b'));
+    /// ```
+    pub fn from_tuple<T: TupleAnyVec>(tuple: T) -> Self {
+        DynTuple {
+            elems: tuple.into_any_vec(),
+        }
+    }
+
+    /// Append an element.
+    pub fn push<T: Any>(&mut self, elem: T) {
+        self.elems.push(Box::new(elem))
+    }
+
+    /// Reference to element `idx`, if it exists and is of type `T`.
+    pub fn get<T: Any>(&self, idx: usize) -> Option<&T> {
+        self.elems.get(idx)?.downcast_ref()
+    }
+
+    /// Mutable reference to element `idx`, if it exists and is of type `T`.
+    pub fn get_mut<T: Any>(&mut self, idx: usize) -> Option<&mut T> {
+        self.elems.get_mut(idx)?.downcast_mut()
+    }
+
+    /// Number of elements.
+    pub fn len(&self) -> usize {
+        self.elems.len()
+    }
+
+    /// True if there are no elements.
+    pub fn is_empty(&self) -> bool {
+        self.elems.is_empty()
+    }
+
+    /// Convert into a statically typed tuple. This fails if the length or any
+    /// element type doesn't match, in which case the elements can be
+    /// recovered from the error with [`FromAnyVecError::into_vec`].
+    pub fn try_into_tuple<T: TupleAnyVec>(self) -> Result<T, FromAnyVecError> {
+        T::from_any_vec(self.elems)
+    }
+
+    /// Unwrap the type-erased elements.
+    pub fn into_vec(self) -> Vec<Box<dyn Any>> {
+        self.elems
+    }
+}
+
+impl From<Vec<Box<dyn Any>>> for DynTuple {
+    fn from(elems: Vec<Box<dyn Any>>) -> Self {
+        DynTuple { elems }
+    }
+}
+
+/// Select an element of a tuple by an index only known at runtime.
+pub trait TupleIdxDyn: seal::Sealed {
+    /// Reference to one of the tuple's elements. This is one of the [`Elem0`]
+    /// ... `ElemN` enums, with a variant per position.
+    type Elem<'a>
+    where
+        Self: 'a;
+
+    /// Return a reference to element `n`, wrapped in the variant for its
+    /// position, or `None` if `n` is out of range.
+    /// ```rust
+    /// # use tuplestructops::{dynamic::Elem3, TupleIdxDyn};
+    /// let t = (1u8, 'a', "b");
+    /// for n in 0..3 {
+    ///     match t.idx_dyn(n).unwrap() {
+    ///         Elem3::Elem0(num) => assert_eq!(*num, 1),
+    ///         Elem3::Elem1(c) => assert_eq!(*c, 'a'),
+    ///         Elem3::Elem2(s) => assert_eq!(*s, "b"),
+    ///     }
+    /// }
+    /// assert!(t.idx_dyn(3).is_none());
+    /// ```
+    fn idx_dyn(&self, n: usize) -> Option<Self::Elem<'_>>;
+}
+
+macro_rules! impl_idx_dyn {
+    (0) => {
+        /// Reference to an element of the unit tuple, which has none.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub enum Elem0 {}
+
+        impl Elem0 {
+            /// Index of the element.
+            pub fn index(&self) -> usize {
+                match *self {}
+            }
+        }
+
+        #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+        impl TupleIdxDyn for () {
+            type Elem<'a> = Elem0;
+
+            fn idx_dyn(&self, _n: usize) -> Option<Elem0> {
+                None
+            }
+        }
+    };
+    ($n:literal) => {
+        seq!(N in $n..=$n { #( seq!(J in 0..N {
+            /// Reference to an element of a tuple, returned by
+            /// [`TupleIdxDyn::idx_dyn`].
+            #[derive(Debug, PartialEq, Eq, Hash)]
+            pub enum Elem~N<'a, #(T~J,)*> {
+                #(
+                    #[doc = concat!("Element ", stringify!(J))]
+                    Elem~J(&'a T~J),
+                )*
+            }
+
+            impl<'a, #(T~J,)*> Elem~N<'a, #(T~J,)*> {
+                /// Index of the element.
+                pub fn index(&self) -> usize {
+                    match *self {
+                        #(Self::Elem~J(_) => J,)*
+                    }
+                }
+            }
+
+            impl<'a, #(T~J,)*> Clone for Elem~N<'a, #(T~J,)*> {
+                fn clone(&self) -> Self {
+                    *self
+                }
+            }
+
+            impl<'a, #(T~J,)*> Copy for Elem~N<'a, #(T~J,)*> {}
+
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<#(T~J,)*> TupleIdxDyn for (#(T~J,)*) {
+                type Elem<'a> = Elem~N<'a, #(T~J,)*>
+                where
+                    Self: 'a;
+
+                fn idx_dyn(&self, n: usize) -> Option<Self::Elem<'_>> {
+                    match n {
+                        #(J => Some(Elem~N::Elem~J(&self.J)),)*
+                        _ => None,
+                    }
+                }
+            }
+        }); )* });
+    };
+}
+
+for_each_tuple!(impl_idx_dyn);
+
+/// Visit each element of a tuple as a `&dyn Any`.
+pub trait TupleForEachDyn: seal::Sealed {
+    /// Call `f` with the index of each element and a reference to it, in
+    /// order. This allows generic inspection of a tuple without implementing
+    /// a trait like [`TryMapper`] for every element type.
+    /// ```rust
+    /// # use std::any::Any;
+    /// # use tuplestructops::TupleForEachDyn;
+    /// let mut strs = Vec::new();
+    /// (1u8, "a", 'b', "c").for_each_dyn(&mut |idx, elem: &dyn Any| {
+    ///     if let Some(s) = elem.downcast_ref::<&str>() {
+    ///         strs.push((idx, *s));
+    ///     }
+    /// });
+    /// assert_eq!(strs, [(1, "a"), (3, "c")]);
+    /// ```
+    fn for_each_dyn(&self, f: &mut dyn FnMut(usize, &dyn Any));
+}
+
+macro_rules! impl_for_each_dyn {
+    ($n:literal) => {
+        seq!(J in 0..$n {
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<#(T~J: Any,)*> TupleForEachDyn for (#(T~J,)*) {
+                #[allow(unused_variables)]
+                fn for_each_dyn(&self, f: &mut dyn FnMut(usize, &dyn Any)) {
+                    #(f(J, &self.J);)*
+                }
+            }
+        });
+    };
+}
+
+for_each_tuple!(impl_for_each_dyn);
+
+/// View each element of a tuple as a trait object.
+///
+/// `D` is the trait object type, such as `dyn MyTrait`, and `N` is the length
+/// of the tuple. This is implemented for a user trait by [`impl_tuple_dyn!`],
+/// for all tuples whose elements implement it.
+pub trait TupleAsDyn<D: ?Sized, const N: usize>: seal::Sealed {
+    /// Array of references to each element as a `D`.
+    fn as_dyn(&self) -> [&D; N];
+
+    /// Array of mutable references to each element as a `D`.
+    fn as_dyn_mut(&mut self) -> [&mut D; N];
+}
+
+/// Implement [`TupleAsDyn`] for a trait, so that every tuple whose elements
+/// implement the trait can be viewed as an array of trait objects.
+///
+/// This must be invoked once, in the crate defining the trait.
+/// ```rust
+/// # use tuplestructops::{impl_tuple_dyn, TupleAsDyn};
+/// trait Shape {
+///     fn area(&self) -> f64;
+///     fn scale(&mut self, by: f64);
+/// }
+///
+/// struct Square(f64);
+/// struct Circle(f64);
+///
+/// impl Shape for Square {
+///     fn area(&self) -> f64 { self.0 * self.0 }
+///     fn scale(&mut self, by: f64) { self.0 *= by }
+/// }
+/// impl Shape for Circle {
+///     fn area(&self) -> f64 { 3.0 * self.0 * self.0 }
+///     fn scale(&mut self, by: f64) { self.0 *= by }
+/// }
+///
+/// impl_tuple_dyn!(Shape);
+///
+/// let mut shapes = (Square(1.0), Circle(1.0), Square(2.0));
+/// for shape in shapes.as_dyn_mut() {
+///     shape.scale(2.0);
+/// }
+/// let total: f64 = shapes.as_dyn().iter().map(|shape| shape.area()).sum();
+/// assert_eq!(total, 4.0 + 12.0 + 16.0);
+/// ```
+#[macro_export]
+macro_rules! impl_tuple_dyn {
+    ($tr:path) => {
+        $crate::__with_max_arity!(impl_tuple_dyn, $tr);
+    };
+    (@arities $max:literal, $tr:path) => {
+        $crate::__seq!(__N in 0..=$max {
+            #( $crate::__seq!(__J in 0..__N {
+                impl<'d, #(__T~__J: $tr + 'd,)*> $crate::TupleAsDyn<dyn $tr + 'd, __N>
+                    for (#(__T~__J,)*)
+                {
+                    fn as_dyn(&self) -> [&(dyn $tr + 'd); __N] {
+                        [#(&self.__J as &(dyn $tr + 'd),)*]
+                    }
+
+                    fn as_dyn_mut(&mut self) -> [&mut (dyn $tr + 'd); __N] {
+                        [#(&mut self.__J as &mut (dyn $tr + 'd),)*]
+                    }
+                }
+            }); )*
+        });
+    };
+}
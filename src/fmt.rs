@@ -0,0 +1,302 @@
+//! Formatting for tuples.
+//!
+//! The standard library only implements `Debug` for tuples of up to 12
+//! elements. [`TupleDebug`] formats any supported tuple size, either in the
+//! standard way or with one indexed element per line. [`TupleDisplay`]
+//! displays the elements with a separator. [`TupleDiff`] and
+//! [`assert_tuple_eq!`](crate::assert_tuple_eq) report which elements of two
+//! tuples differ.
+use std::fmt::{self, Debug, Display, Formatter, Write};
+
+use super::*;
+
+/// Debug formatting for tuples of any supported size.
+pub trait TupleDebug: seal::Sealed {
+    /// Format the tuple in the same way as the standard `Debug` impl for
+    /// tuples, including alternate (`{:#?}`) formatting.
+    fn fmt_debug(&self, f: &mut Formatter<'_>) -> fmt::Result;
+
+    /// Wrap the tuple in a [`DebugTuple`], which implements `Debug`.
+    /// ```rust
+    /// # use tuplestructops::TupleDebug;
+    /// let wide = (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13);
+    /// assert_eq!(
+    ///     format!("{:?}", wide.debug()),
+    ///     "(0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13)"
+    /// );
+    /// assert_eq!(format!("{:?}", (1,).debug()), format!("{:?}", (1,)));
+    /// ```
+    fn debug(&self) -> DebugTuple<'_, Self> {
+        DebugTuple(self)
+    }
+
+    /// Format the tuple with one element per line, prefixed by its index.
+    fn fmt_indexed(&self, f: &mut Formatter<'_>) -> fmt::Result;
+
+    /// Wrap the tuple in a [`DebugIndexed`], which implements `Debug` by
+    /// formatting one element per line prefixed with its index. This makes
+    /// wide tuples much easier to read.
+    /// ```rust
+    /// # use tuplestructops::TupleDebug;
+    /// assert_eq!(
+    ///     format!("{:?}", (1, "a", [2, 3]).debug_indexed()),
+    ///     "(\n    0: 1,\n    1: \"a\",\n    2: [\n        2,\n        3,\n    ],\n)"
+    /// );
+    /// ```
+    fn debug_indexed(&self) -> DebugIndexed<'_, Self> {
+        DebugIndexed(self)
+    }
+}
+
+/// Wrapper for a tuple which implements `Debug`. See [`TupleDebug::debug`].
+pub struct DebugTuple<'a, T: ?Sized>(&'a T);
+
+impl<T: TupleDebug + ?Sized> Debug for DebugTuple<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.0.fmt_debug(f)
+    }
+}
+
+/// Wrapper for a tuple which implements `Debug` with one element per line. See
+/// [`TupleDebug::debug_indexed`].
+pub struct DebugIndexed<'a, T: ?Sized>(&'a T);
+
+impl<T: TupleDebug + ?Sized> Debug for DebugIndexed<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.0.fmt_indexed(f)
+    }
+}
+
+/// Display formatting for tuples whose elements are all `Display`.
+pub trait TupleDisplay: seal::Sealed {
+    /// Write each element with its `Display` impl, separated by `sep`. The
+    /// formatter's options, such as width and precision, apply to each
+    /// element.
+    fn fmt_join(&self, sep: &str, f: &mut Formatter<'_>) -> fmt::Result;
+
+    /// Wrap the tuple in a [`DisplayJoin`], which implements `Display` by
+    /// writing each element separated by `sep`.
+    /// ```rust
+    /// # use tuplestructops::TupleDisplay;
+    /// let t = (1, "apples", 2.5);
+    /// assert_eq!(t.display_join(", ").to_string(), "1, apples, 2.5");
+    /// assert_eq!(format!("[{:>3}]", (1, 2).display_join("|")), "[  1|  2]");
+    /// ```
+    fn display_join<'a>(&'a self, sep: &'a str) -> DisplayJoin<'a, Self> {
+        DisplayJoin { tuple: self, sep }
+    }
+}
+
+/// Wrapper for a tuple which implements `Display` by writing each element with
+/// a separator. See [`TupleDisplay::display_join`].
+pub struct DisplayJoin<'a, T: ?Sized> {
+    tuple: &'a T,
+    sep: &'a str,
+}
+
+impl<T: TupleDisplay + ?Sized> Display for DisplayJoin<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.tuple.fmt_join(self.sep, f)
+    }
+}
+
+/// A pair of corresponding elements which differ, found by [`TupleDiff::diff`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ElemDiff {
+    /// Index of the elements.
+    pub index: usize,
+    /// `Debug` formatting of the left element.
+    pub left: String,
+    /// `Debug` formatting of the right element.
+    pub right: String,
+}
+
+/// Elementwise comparison of tuples of any supported size.
+pub trait TupleDiff: seal::Sealed {
+    /// Compare each element with the corresponding element of `other`, and
+    /// return the ones which aren't equal, in index order.
+    /// ```rust
+    /// # use tuplestructops::fmt::{ElemDiff, TupleDiff};
+    /// let diffs = (1, "a", 2.5).diff(&(1, "b", 2.5));
+    /// assert_eq!(
+    ///     diffs,
+    ///     [ElemDiff { index: 1, left: "\"a\"".into(), right: "\"b\"".into() }]
+    /// );
+    /// ```
+    fn diff(&self, other: &Self) -> Vec<ElemDiff>;
+}
+
+/// Assert that two tuples are equal, reporting each differing element.
+///
+/// This is like `assert_eq!`, but rather than printing both tuples in full on
+/// failure, it prints the index and values of just the elements which differ.
+/// It works for tuples of any supported size, as long as their elements
+/// implement `PartialEq` and `Debug`. An optional message can be given with
+/// `format!` style arguments.
+/// ```rust
+/// # use tuplestructops::assert_tuple_eq;
+/// assert_tuple_eq!((1, "a", 2.5), (1, "a", 2.5));
+/// ```
+/// ```rust,should_panic
+/// # use tuplestructops::assert_tuple_eq;
+/// // Panics with:
+/// // assertion `left == right` failed: row 3
+/// // 1 of 14 elements differ
+/// // at index 9:
+/// //   left: 9
+/// //  right: 0
+/// let row = 3;
+/// assert_tuple_eq!(
+///     (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13),
+///     (0, 1, 2, 3, 4, 5, 6, 7, 8, 0, 10, 11, 12, 13),
+///     "row {}", row
+/// );
+/// ```
+#[macro_export]
+macro_rules! assert_tuple_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                let diffs = $crate::fmt::TupleDiff::diff(left, right);
+                if !diffs.is_empty() {
+                    $crate::fmt::assert_tuple_eq_failed(
+                        $crate::TupleLen::len(left),
+                        &diffs,
+                        ::core::option::Option::None,
+                    );
+                }
+            }
+        }
+    };
+    ($left:expr, $right:expr, $($arg:tt)+) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                let diffs = $crate::fmt::TupleDiff::diff(left, right);
+                if !diffs.is_empty() {
+                    $crate::fmt::assert_tuple_eq_failed(
+                        $crate::TupleLen::len(left),
+                        &diffs,
+                        ::core::option::Option::Some(::core::format_args!($($arg)+)),
+                    );
+                }
+            }
+        }
+    };
+}
+
+#[doc(hidden)]
+#[track_caller]
+pub fn assert_tuple_eq_failed(len: usize, diffs: &[ElemDiff], msg: Option<fmt::Arguments>) -> ! {
+    let mut out = String::from("assertion `left == right` failed");
+    if let Some(msg) = msg {
+        let _ = write!(out, ": {}", msg);
+    }
+    let _ = write!(out, "\n{} of {} elements differ", diffs.len(), len);
+    for diff in diffs {
+        let _ = write!(
+            out,
+            "\nat index {}:\n  left: {}\n right: {}",
+            diff.index, diff.left, diff.right
+        );
+    }
+    panic!("{}", out)
+}
+
+// Indent everything written through it by one level.
+struct PadAdapter<'a, 'b> {
+    f: &'a mut Formatter<'b>,
+    on_newline: bool,
+}
+
+impl Write for PadAdapter<'_, '_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for line in s.split_inclusive('\n') {
+            if self.on_newline {
+                self.f.write_str("    ")?;
+            }
+            self.on_newline = line.ends_with('\n');
+            self.f.write_str(line)?;
+        }
+        Ok(())
+    }
+}
+
+macro_rules! impl_fmt {
+    (0) => {
+        #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+        impl TupleDebug for () {
+            fn fmt_debug(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                f.pad("()")
+            }
+
+            fn fmt_indexed(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                f.pad("()")
+            }
+        }
+
+        #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+        impl TupleDiff for () {
+            fn diff(&self, _other: &Self) -> Vec<ElemDiff> {
+                Vec::new()
+            }
+        }
+
+        #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+        impl TupleDisplay for () {
+            fn fmt_join(&self, _sep: &str, _f: &mut Formatter<'_>) -> fmt::Result {
+                Ok(())
+            }
+        }
+    };
+    ($n:literal) => {
+        seq!(J in 0..$n {
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<#(T~J: Debug,)*> TupleDebug for (#(T~J,)*) {
+                fn fmt_debug(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                    f.debug_tuple("")
+                        #(.field(&self.J))*
+                        .finish()
+                }
+
+                fn fmt_indexed(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                    f.write_str("(\n")?;
+                    let mut pad = PadAdapter { f, on_newline: true };
+                    #(writeln!(pad, "{}: {:#?},", J, self.J)?;)*
+                    f.write_str(")")
+                }
+            }
+
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<#(T~J: PartialEq + Debug,)*> TupleDiff for (#(T~J,)*) {
+                fn diff(&self, other: &Self) -> Vec<ElemDiff> {
+                    let mut diffs = Vec::new();
+                    #(
+                        if self.J != other.J {
+                            diffs.push(ElemDiff {
+                                index: J,
+                                left: format!("{:?}", self.J),
+                                right: format!("{:?}", other.J),
+                            });
+                        }
+                    )*
+                    diffs
+                }
+            }
+
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<#(T~J: Display,)*> TupleDisplay for (#(T~J,)*) {
+                fn fmt_join(&self, sep: &str, f: &mut Formatter<'_>) -> fmt::Result {
+                    #(
+                        if J > 0 {
+                            f.write_str(sep)?;
+                        }
+                        Display::fmt(&self.J, f)?;
+                    )*
+                    Ok(())
+                }
+            }
+        });
+    };
+}
+
+for_each_tuple!(impl_fmt);
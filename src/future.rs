@@ -0,0 +1,494 @@
+//! Asynchronous operations on tuples.
+//!
+//! These are enabled with the `futures` feature. The traits are re-exported at
+//! the crate root.
+use std::future::Future;
+use std::marker::PhantomData;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures::future::MaybeDone;
+use futures::stream::{FusedStream, Stream};
+use pin_project_lite::pin_project;
+
+use super::*;
+
+pin_project! {
+    /// Future which concurrently polls a tuple of futures, resolving to the
+    /// tuple of their outputs once they have all completed.
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct JoinTuple<T: PinnedTuple> {
+        #[pin]
+        futs: T::Pinned,
+    }
+}
+
+pin_project! {
+    /// Future which concurrently polls a tuple of fallible futures, resolving
+    /// to the tuple of their successful outputs, or the first error.
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct TryJoinTuple<T: PinnedTuple, E> {
+        #[pin]
+        pub(crate) futs: T::Pinned,
+        pub(crate) _error: PhantomData<fn() -> E>,
+    }
+}
+
+pin_project! {
+    /// Future which polls a tuple of futures, resolving to the output of the
+    /// first to complete. See [`TupleRace::race`].
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct Race<T: PinnedTuple> {
+        #[pin]
+        futs: T::Pinned,
+    }
+}
+
+pin_project! {
+    /// Stream of tuples of items from a tuple of streams. See
+    /// [`TupleZipStreams::zip_streams`].
+    ///
+    /// This is fused: once any of the streams ends, it keeps returning `None`
+    /// without polling the streams again.
+    #[must_use = "streams do nothing unless polled"]
+    pub struct ZipStreams<S: PinnedTuple, T> {
+        #[pin]
+        streams: S::Pinned,
+        items: T,
+        done: bool,
+    }
+}
+
+/// Storage for a tuple of futures or streams with each element pinned
+/// separately, so that they can be polled without `unsafe` projections.
+#[doc(hidden)]
+pub trait PinnedTuple: seal::Sealed {
+    /// Struct with a pinned field per element.
+    type Pinned;
+
+    /// Move the elements into the struct.
+    fn into_pinned(self) -> Self::Pinned;
+}
+
+macro_rules! impl_pinned {
+    (0) => {
+        pin_project! {
+            #[doc(hidden)]
+            pub struct Pinned0 {
+                _empty: (),
+            }
+        }
+
+        impl PinnedTuple for () {
+            type Pinned = Pinned0;
+
+            fn into_pinned(self) -> Self::Pinned {
+                Pinned0 { _empty: () }
+            }
+        }
+    };
+    ($n:literal) => {
+        seq!(N in $n..=$n { #( seq!(J in 0..N {
+            pin_project! {
+                #[doc(hidden)]
+                pub struct Pinned~N<#(T~J,)*> {
+                    #(
+                        #[pin]
+                        e~J: T~J,
+                    )*
+                }
+            }
+
+            impl<#(T~J,)*> PinnedTuple for (#(T~J,)*) {
+                type Pinned = Pinned~N<#(T~J,)*>;
+
+                fn into_pinned(self) -> Self::Pinned {
+                    Pinned~N { #(e~J: self.J,)* }
+                }
+            }
+        }); )* });
+    };
+}
+
+for_each_tuple!(impl_pinned);
+
+/// Asynchronous mapping of a single element, used by [`TupleMapAsync`].
+///
+/// An `AsyncMapper` must be implemented for every element type of the tuple
+/// being mapped, typically via a generic impl.
+pub trait AsyncMapper<T> {
+    /// Future resolving to the mapped element.
+    type Future: Future;
+
+    /// Map a single element into a future.
+    fn map_async(&mut self, elem: T) -> Self::Future;
+}
+
+/// Asynchronously map each element of a tuple with an [`AsyncMapper`].
+pub trait TupleMapAsync<M>: seal::Sealed {
+    /// Tuple of mapped elements.
+    type Output;
+    /// Future resolving to the mapped tuple.
+    type Future: Future<Output = Self::Output>;
+
+    /// Map each element of the tuple into a future, and return a future which
+    /// awaits them all concurrently.
+    /// ```rust
+    /// # use tuplestructops::{AsyncMapper, TupleMapAsync};
+    /// use std::future::{ready, Ready};
+    ///
+    /// struct Describe;
+    ///
+    /// impl<T: std::fmt::Debug> AsyncMapper<T> for Describe {
+    ///     type Future = Ready<String>;
+    ///
+    ///     fn map_async(&mut self, elem: T) -> Self::Future {
+    ///         ready(format!("{elem:?}"))
+    ///     }
+    /// }
+    ///
+    /// let out = futures::executor::block_on((1, 'a').map_async(Describe));
+    /// assert_eq!(out, ("1".to_string(), "'a'".to_string()));
+    /// ```
+    fn map_async(self, mapper: M) -> Self::Future;
+}
+
+/// Join a tuple of futures into a single future.
+pub trait TupleJoinAll: seal::Sealed {
+    /// Future resolving to the tuple of outputs.
+    type Future: Future;
+
+    /// Return a future which polls all the futures concurrently, and resolves
+    /// to the tuple of their outputs once they have all completed. This is like
+    /// [`futures::join!`] for any tuple size.
+    /// ```rust
+    /// # use tuplestructops::TupleJoinAll;
+    /// use std::future::ready;
+    ///
+    /// let fut = (ready(1), async { 'a' }, ready("b")).join_all_tuple();
+    /// assert_eq!(futures::executor::block_on(fut), (1, 'a', "b"));
+    /// ```
+    fn join_all_tuple(self) -> Self::Future;
+}
+
+/// Join a tuple of fallible futures with the same error type `E` into a single
+/// future.
+pub trait TupleTryJoin<E>: seal::Sealed {
+    /// Future resolving to the tuple of outputs, or the first error.
+    type Future: Future;
+
+    /// Return a future which polls all the futures concurrently. It resolves
+    /// to the tuple of their `Ok` outputs once they have all completed, or
+    /// as soon as any of them fails with the error. This is like
+    /// [`futures::try_join!`] for any tuple size.
+    /// ```rust
+    /// # use tuplestructops::TupleTryJoin;
+    /// use std::future::{pending, ready};
+    ///
+    /// let fut = (ready(Ok::<_, ()>(1)), async { Ok('a') }).try_join_tuple();
+    /// assert_eq!(futures::executor::block_on(fut), Ok((1, 'a')));
+    ///
+    /// let fut = (pending::<Result<u8, _>>(), ready(Err::<char, _>("failed"))).try_join_tuple();
+    /// assert_eq!(futures::executor::block_on(fut), Err("failed"));
+    /// ```
+    fn try_join_tuple(self) -> Self::Future;
+}
+
+/// Race a tuple of futures against each other.
+pub trait TupleRace: seal::Sealed {
+    /// Future resolving to an `EitherN` enum of the first output.
+    type Future: Future;
+
+    /// Return a future which polls all the futures, and resolves to the output
+    /// of the first one to complete. The output is wrapped in an `EitherN`
+    /// enum (such as [`Either3`]) whose variant identifies which future it
+    /// was. The remaining futures are dropped with the `Race` future.
+    ///
+    /// The futures are polled in order, so if several are ready at once the
+    /// earliest wins.
+    /// ```rust
+    /// # use tuplestructops::TupleRace;
+    /// use std::future::{pending, ready};
+    /// use tuplestructops::future::Either3;
+    ///
+    /// let fut = (pending::<u8>(), ready('a'), ready("b")).race();
+    /// assert_eq!(futures::executor::block_on(fut), Either3::E1('a'));
+    /// ```
+    /// This isn't implemented for the unit tuple, since it could never
+    /// complete.
+    fn race(self) -> Self::Future;
+}
+
+/// Zip a tuple of streams into a stream of tuples.
+pub trait TupleZipStreams: seal::Sealed {
+    /// Stream of tuples of items.
+    type Stream: Stream;
+
+    /// Return a stream which polls all the streams concurrently, yielding a
+    /// tuple once each has produced an item. It ends when any of the streams
+    /// ends.
+    /// ```rust
+    /// # use tuplestructops::TupleZipStreams;
+    /// use futures::stream::{iter, StreamExt};
+    ///
+    /// let zipped = (iter(1..4), iter("ab".chars())).zip_streams();
+    /// let items: Vec<_> = futures::executor::block_on(zipped.collect());
+    /// assert_eq!(items, [(1, 'a'), (2, 'b')]);
+    /// ```
+    /// This isn't implemented for the unit tuple.
+    fn zip_streams(self) -> Self::Stream;
+}
+
+macro_rules! impl_future {
+    ($n:literal) => {
+        seq!(J in 0..$n {
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<#(F~J: Future,)*> Future for JoinTuple<(#(MaybeDone<F~J>,)*)> {
+                type Output = (#(F~J::Output,)*);
+
+                #[allow(clippy::unused_unit, unused_variables, unused_mut)]
+                fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+                    let mut futs = self.project().futs.project();
+                    let mut done = true;
+
+                    #(
+                        done &= futs.e~J.as_mut().poll(cx).is_ready();
+                    )*
+
+                    if !done {
+                        return Poll::Pending;
+                    }
+
+                    Poll::Ready((#(
+                        futs.e~J
+                            .as_mut()
+                            .take_output()
+                            .expect("JoinTuple polled after completion"),
+                    )*))
+                }
+            }
+
+            // Map by value
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<M, #(T~J,)*> TupleMapAsync<M> for (#(T~J,)*)
+            where
+                #(M: AsyncMapper<T~J>,)*
+            {
+                type Output = (#(<<M as AsyncMapper<T~J>>::Future as Future>::Output,)*);
+                type Future = JoinTuple<(#(MaybeDone<<M as AsyncMapper<T~J>>::Future>,)*)>;
+
+                #[allow(non_snake_case, unused_variables, unused_mut)]
+                fn map_async(self, mut mapper: M) -> Self::Future {
+                    let (#(T~J,)*) = self;
+
+                    JoinTuple {
+                        futs: (#(MaybeDone::Future(mapper.map_async(T~J)),)*).into_pinned(),
+                    }
+                }
+            }
+
+            // Map by reference
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<'a, M, #(T~J,)*> TupleMapAsync<M> for &'a (#(T~J,)*)
+            where
+                #(M: AsyncMapper<&'a T~J>,)*
+            {
+                type Output = (#(<<M as AsyncMapper<&'a T~J>>::Future as Future>::Output,)*);
+                type Future = JoinTuple<(#(MaybeDone<<M as AsyncMapper<&'a T~J>>::Future>,)*)>;
+
+                #[allow(non_snake_case, unused_variables, unused_mut)]
+                fn map_async(self, mut mapper: M) -> Self::Future {
+                    let (#(T~J,)*) = self;
+
+                    JoinTuple {
+                        futs: (#(MaybeDone::Future(mapper.map_async(T~J)),)*).into_pinned(),
+                    }
+                }
+            }
+
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<#(F~J: Future,)*> TupleJoinAll for (#(F~J,)*) {
+                type Future = JoinTuple<(#(MaybeDone<F~J>,)*)>;
+
+                #[allow(non_snake_case)]
+                fn join_all_tuple(self) -> Self::Future {
+                    let (#(F~J,)*) = self;
+
+                    JoinTuple {
+                        futs: (#(MaybeDone::Future(F~J),)*).into_pinned(),
+                    }
+                }
+            }
+
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<E, #(F~J, T~J,)*> Future for TryJoinTuple<(#(MaybeDone<F~J>,)*), E>
+            where
+                #(F~J: Future<Output = Result<T~J, E>>,)*
+            {
+                type Output = Result<(#(T~J,)*), E>;
+
+                #[allow(clippy::unused_unit, unused_variables, unused_mut)]
+                fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+                    let mut futs = self.project().futs.project();
+                    let mut done = true;
+
+                    #(
+                        let mut fut = futs.e~J.as_mut();
+                        if fut.as_mut().poll(cx).is_ready() {
+                            if let Some(Err(_)) = fut.as_mut().output_mut() {
+                                if let Some(Err(err)) = fut.take_output() {
+                                    return Poll::Ready(Err(err));
+                                }
+                            }
+                        } else {
+                            done = false;
+                        }
+                    )*
+
+                    if !done {
+                        return Poll::Pending;
+                    }
+
+                    Poll::Ready(Ok((#(
+                        match futs.e~J.as_mut().take_output() {
+                            Some(Ok(out)) => out,
+                            _ => panic!("TryJoinTuple polled after completion"),
+                        },
+                    )*)))
+                }
+            }
+
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<E, #(F~J, T~J,)*> TupleTryJoin<E> for (#(F~J,)*)
+            where
+                #(F~J: Future<Output = Result<T~J, E>>,)*
+            {
+                type Future = TryJoinTuple<(#(MaybeDone<F~J>,)*), E>;
+
+                #[allow(non_snake_case)]
+                fn try_join_tuple(self) -> Self::Future {
+                    let (#(F~J,)*) = self;
+
+                    TryJoinTuple {
+                        futs: (#(MaybeDone::Future(F~J),)*).into_pinned(),
+                        _error: PhantomData,
+                    }
+                }
+            }
+        });
+    };
+}
+
+for_each_tuple!(impl_future);
+
+macro_rules! impl_race {
+    (0) => {};
+    ($n:literal) => {
+        seq!(N in $n..=$n {
+            #(seq!(J in 0..N {
+                /// Output of [`TupleRace::race`] for a tuple of N futures,
+                /// identifying which future completed first.
+                #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+                pub enum Either~N<#(T~J,)*> {
+                    #(
+                        /// Output of the future with the same index as the variant.
+                        E~J(T~J),
+                    )*
+                }
+
+                #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+                impl<#(F~J: Future,)*> Future for Race<(#(F~J,)*)> {
+                    type Output = Either~N<#(F~J::Output,)*>;
+
+                    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+                        let mut futs = self.project().futs.project();
+
+                        #(
+                            if let Poll::Ready(out) = futs.e~J.as_mut().poll(cx) {
+                                return Poll::Ready(Either~N::E~J(out));
+                            }
+                        )*
+
+                        Poll::Pending
+                    }
+                }
+
+                #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+                impl<#(F~J: Future,)*> TupleRace for (#(F~J,)*) {
+                    type Future = Race<Self>;
+
+                    fn race(self) -> Self::Future {
+                        Race {
+                            futs: self.into_pinned(),
+                        }
+                    }
+                }
+            });)*
+        });
+    };
+}
+
+for_each_tuple!(impl_race);
+
+macro_rules! impl_zip_streams {
+    (0) => {};
+    ($n:literal) => {
+        seq!(J in 0..$n {
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<#(S~J: Stream,)*> Stream for ZipStreams<(#(S~J,)*), (#(Option<S~J::Item>,)*)> {
+                type Item = (#(S~J::Item,)*);
+
+                fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+                    let this = self.project();
+                    if *this.done {
+                        return Poll::Ready(None);
+                    }
+                    let mut streams = this.streams.project();
+
+                    #(
+                        if !*this.done && this.items.J.is_none() {
+                            match streams.e~J.as_mut().poll_next(cx) {
+                                Poll::Ready(Some(item)) => this.items.J = Some(item),
+                                Poll::Ready(None) => *this.done = true,
+                                Poll::Pending => {}
+                            }
+                        }
+                    )*
+
+                    if *this.done {
+                        // Drop any items buffered from the other streams
+                        *this.items = (#(None::<S~J::Item>,)*);
+                        return Poll::Ready(None);
+                    }
+
+                    Poll::Ready(Some(match this.items {
+                        (#(item~J @ Some(_),)*) => (#(item~J.take().unwrap(),)*),
+                        _ => return Poll::Pending,
+                    }))
+                }
+            }
+
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<#(S~J: Stream,)*> FusedStream for ZipStreams<(#(S~J,)*), (#(Option<S~J::Item>,)*)> {
+                fn is_terminated(&self) -> bool {
+                    self.done
+                }
+            }
+
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<#(S~J: Stream,)*> TupleZipStreams for (#(S~J,)*) {
+                type Stream = ZipStreams<Self, (#(Option<S~J::Item>,)*)>;
+
+                fn zip_streams(self) -> Self::Stream {
+                    ZipStreams {
+                        streams: self.into_pinned(),
+                        items: (#(None::<S~J::Item>,)*),
+                        done: false,
+                    }
+                }
+            }
+        });
+    };
+}
+
+for_each_tuple!(impl_zip_streams);
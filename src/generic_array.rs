@@ -0,0 +1,59 @@
+//! typenum lengths and [`GenericArray`] conversions for tuples.
+//!
+//! [`TupleTypenum`] gives each tuple's length as a `typenum` type, and
+//! [`TupleGenericArray`] converts between homogeneous tuples and
+//! `GenericArray`s of the same length. The `typenum` types are the ones
+//! re-exported by `generic_array`.
+//! ```rust
+//! # use generic_array::{typenum::{Unsigned, U3}, GenericArray};
+//! # use tuplestructops::generic_array::{TupleGenericArray, TupleTypenum};
+//! assert_eq!(<(u8, char, bool) as TupleTypenum>::Len::USIZE, 3);
+//!
+//! let arr: GenericArray<i32, U3> = (1, 2, 3).into_generic_array();
+//! assert_eq!(arr.as_slice(), &[1, 2, 3]);
+//! assert_eq!(<(i32, i32, i32)>::from_generic_array(arr), (1, 2, 3));
+//! ```
+use ::generic_array::typenum::{self, Unsigned};
+use ::generic_array::{ArrayLength, GenericArray};
+
+use super::*;
+
+/// Length of a tuple as a `typenum` type.
+pub trait TupleTypenum: seal::Sealed {
+    /// `typenum` unsigned integer type for the number of elements, such as
+    /// `U3` for a 3-tuple.
+    type Len: Unsigned + ArrayLength;
+}
+
+/// Conversion between homogeneous tuples and `GenericArray`s.
+pub trait TupleGenericArray<T>: HomogeneousTuple<T> + TupleTypenum {
+    /// Move the elements into a `GenericArray` of the same length.
+    fn into_generic_array(self) -> GenericArray<T, Self::Len>;
+
+    /// Construct a tuple from the elements of a `GenericArray`.
+    fn from_generic_array(array: GenericArray<T, Self::Len>) -> Self;
+}
+
+macro_rules! impl_generic_array {
+    ($n:literal) => {
+        seq!(N in $n..=$n { #( seq!(J in 0..N {
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<#(T~J,)*> TupleTypenum for (#(T~J,)*) {
+                type Len = typenum::U~N;
+            }
+
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<T> TupleGenericArray<T> for (#(T,)*) {
+                fn into_generic_array(self) -> GenericArray<T, Self::Len> {
+                    GenericArray::from_array(self.into_array())
+                }
+
+                fn from_generic_array(array: GenericArray<T, Self::Len>) -> Self {
+                    Self::from_array(array.into_array())
+                }
+            }
+        }); )* });
+    };
+}
+
+for_each_tuple!(impl_generic_array);
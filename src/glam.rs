@@ -0,0 +1,77 @@
+//! Conversions between 2, 3 and 4 element tuples and [`glam`] vectors.
+//!
+//! `glam` itself implements `From` in both directions between its vector
+//! types and tuples, such as `(f32, f32, f32)` and [`Vec3`](::glam::Vec3).
+//! Those need the target type to be spelled out, which is awkward in generic
+//! code. [`TupleGlam`] names the vector type for each tuple type, so the
+//! conversion can be inferred from the tuple alone:
+//! ```rust
+//! # use glam::{IVec2, Vec3};
+//! # use tuplestructops::glam::TupleGlam;
+//! let v = (1.0f32, 2.0, 2.0).into_glam();
+//! assert_eq!(v.length(), 3.0);
+//! assert_eq!(<(f32, f32, f32)>::from_glam(v * 2.0), (2.0, 4.0, 4.0));
+//!
+//! assert_eq!((3i32, -4).into_glam().abs(), IVec2::new(3, 4));
+//!
+//! // glam's own conversions work too
+//! let v: Vec3 = (1.0, 2.0, 3.0).into();
+//! assert_eq!(<(f32, f32, f32)>::from(v), (1.0, 2.0, 3.0));
+//! ```
+use super::*;
+
+/// Conversion between tuples and the `glam` vector type with the same element
+/// type and length.
+///
+/// This is implemented for tuples of 2, 3 or 4 elements of the same scalar
+/// type, for every scalar type `glam` has vectors of: `f32` (`Vec2`, `Vec3`
+/// and `Vec4`), `f64` (`DVec2` ...), `i32` (`IVec2` ...), `u32` (`UVec2`
+/// ...), and the other integer types (`I8Vec2`, `U64Vec2`, `USizeVec2` ...).
+pub trait TupleGlam: seal::Sealed {
+    /// `glam` vector type for this tuple type.
+    type Glam;
+
+    /// Convert the tuple into a `glam` vector.
+    fn into_glam(self) -> Self::Glam;
+
+    /// Construct a tuple from the components of a `glam` vector.
+    fn from_glam(vector: Self::Glam) -> Self;
+}
+
+macro_rules! impl_glam {
+    ($($t:ty => $v2:ident, $v3:ident, $v4:ident;)*) => {
+        $(
+            impl_glam!(@impl ($t, $t), $v2);
+            impl_glam!(@impl ($t, $t, $t), $v3);
+            impl_glam!(@impl ($t, $t, $t, $t), $v4);
+        )*
+    };
+    (@impl $tuple:ty, $vec:ident) => {
+        #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+        impl TupleGlam for $tuple {
+            type Glam = ::glam::$vec;
+
+            fn into_glam(self) -> Self::Glam {
+                self.into()
+            }
+
+            fn from_glam(vector: Self::Glam) -> Self {
+                vector.into()
+            }
+        }
+    };
+}
+
+impl_glam! {
+    f32 => Vec2, Vec3, Vec4;
+    f64 => DVec2, DVec3, DVec4;
+    i8 => I8Vec2, I8Vec3, I8Vec4;
+    u8 => U8Vec2, U8Vec3, U8Vec4;
+    i16 => I16Vec2, I16Vec3, I16Vec4;
+    u16 => U16Vec2, U16Vec3, U16Vec4;
+    i32 => IVec2, IVec3, IVec4;
+    u32 => UVec2, UVec3, UVec4;
+    i64 => I64Vec2, I64Vec3, I64Vec4;
+    u64 => U64Vec2, U64Vec3, U64Vec4;
+    usize => USizeVec2, USizeVec3, USizeVec4;
+}
@@ -0,0 +1,48 @@
+//! Conversions between homogeneous tuples and [`heapless::Vec`]s.
+//!
+//! A tuple converts into a full `heapless::Vec` with the same capacity as its
+//! length. Converting back accepts a `heapless::Vec` of any capacity, and
+//! checks the length:
+//! ```rust
+//! # use tuplestructops::heapless::TupleHeaplessVec;
+//! let v: heapless::Vec<u8, 3> = (1, 2, 3).into_heapless_vec();
+//! assert!(v.is_full());
+//!
+//! let v: heapless::Vec<u8, 8> = heapless::Vec::from_slice(&[4, 5]).unwrap();
+//! assert_eq!(<(u8, u8)>::from_heapless_vec(v), Ok((4, 5)));
+//! ```
+use ::heapless::Vec;
+
+use super::homogeneous::LengthError;
+use super::*;
+
+/// Conversion between homogeneous tuples of length `N` and `heapless::Vec`s.
+pub trait TupleHeaplessVec<T, const N: usize>: HomogeneousTuple<T> {
+    /// Move the elements into a full `heapless::Vec` of capacity `N`.
+    fn into_heapless_vec(self) -> Vec<T, N>;
+
+    /// Move the elements of a `heapless::Vec` of any capacity into a tuple,
+    /// failing if it's not the same length as the tuple.
+    fn from_heapless_vec<const CAP: usize>(vec: Vec<T, CAP>) -> Result<Self, LengthError>;
+}
+
+macro_rules! impl_heapless {
+    ($n:literal) => {
+        seq!(J in 0..$n {
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<T> TupleHeaplessVec<T, $n> for (#(T,)*) {
+                fn into_heapless_vec(self) -> Vec<T, $n> {
+                    Vec::from_array(self.into_array())
+                }
+
+                fn from_heapless_vec<const CAP: usize>(vec: Vec<T, CAP>) -> Result<Self, LengthError> {
+                    vec.into_array::<$n>()
+                        .map(Self::from_array)
+                        .map_err(|vec| LengthError::new($n, vec.len()))
+                }
+            }
+        });
+    };
+}
+
+for_each_tuple!(impl_heapless);
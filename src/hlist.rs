@@ -0,0 +1,67 @@
+//! Conversions between tuples and [frunk](https://docs.rs/frunk) `HList`s.
+//!
+//! The `HList` types come from `frunk_core`, which `frunk` re-exports, so
+//! these work with either crate. Conversions are lossless in both directions,
+//! so a value can move between this crate's operations and frunk's.
+//! ```rust
+//! # use frunk_core::hlist;
+//! # use tuplestructops::hlist::{HListIntoTuple, TupleIntoHList};
+//! # use tuplestructops::TupleJoin;
+//! let h = (1, "two").join((3.0,)).into_hlist();
+//! assert_eq!(h, hlist![1, "two", 3.0]);
+//! assert_eq!(h.into_tuple(), (1, "two", 3.0));
+//! ```
+use frunk_core::{hlist, hlist_pat, HList};
+
+use super::*;
+
+/// Convert a tuple into the equivalent `HList`.
+pub trait TupleIntoHList: seal::Sealed {
+    /// `HList` with the same element types, in the same order.
+    type HList;
+
+    /// Move each element into an `HList`.
+    fn into_hlist(self) -> Self::HList;
+}
+
+/// Convert an `HList` into the equivalent tuple.
+///
+/// This is implemented for `HList`s with as many elements as the largest
+/// supported tuple.
+pub trait HListIntoTuple {
+    /// Tuple with the same element types, in the same order.
+    type Tuple;
+
+    /// Move each element into a tuple.
+    fn into_tuple(self) -> Self::Tuple;
+}
+
+macro_rules! impl_hlist {
+    ($n:literal) => {
+        seq!(J in 0..$n {
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<#(T~J,)*> TupleIntoHList for (#(T~J,)*) {
+                type HList = HList![#(T~J,)*];
+
+                #[allow(non_snake_case)]
+                fn into_hlist(self) -> Self::HList {
+                    let (#(T~J,)*) = self;
+                    hlist![#(T~J,)*]
+                }
+            }
+
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<#(T~J,)*> HListIntoTuple for HList![#(T~J,)*] {
+                type Tuple = (#(T~J,)*);
+
+                #[allow(non_snake_case, clippy::unused_unit)]
+                fn into_tuple(self) -> Self::Tuple {
+                    let hlist_pat![#(T~J,)*] = self;
+                    (#(T~J,)*)
+                }
+            }
+        });
+    };
+}
+
+for_each_tuple!(impl_hlist);
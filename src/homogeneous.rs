@@ -0,0 +1,848 @@
+//! Operations on homogeneous tuples, whose elements all have the same type.
+//!
+//! [`HomogeneousTuple`] is re-exported at the crate root. This module also
+//! contains the error type for conversions from slices and `Vec`s.
+use std::cmp::Ordering;
+use std::error::Error;
+use std::fmt;
+use std::iter::{Product, Sum};
+use std::ops::{Add, Mul, Sub};
+
+use super::*;
+
+mod float_seal {
+    pub trait Sealed {}
+}
+
+/// Primitive floating point types, used for [`HomogeneousTuple::length`] and
+/// [`HomogeneousTuple::lerp`].
+pub trait Float:
+    float_seal::Sealed + Copy + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Sum
+{
+    #[doc(hidden)]
+    fn sqrt(self) -> Self;
+}
+
+macro_rules! impl_float {
+    ($($ty:ident)*) => {
+        $(
+            impl float_seal::Sealed for $ty {}
+
+            impl Float for $ty {
+                fn sqrt(self) -> Self {
+                    $ty::sqrt(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_float!(f32 f64);
+
+/// Error converting a slice or `Vec` into a tuple of a different length.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LengthError {
+    expected: usize,
+    found: usize,
+}
+
+impl LengthError {
+    pub(crate) fn new(expected: usize, found: usize) -> Self {
+        LengthError { expected, found }
+    }
+
+    /// Length of the tuple being converted into.
+    pub fn expected(&self) -> usize {
+        self.expected
+    }
+
+    /// Length of the slice or `Vec` being converted from.
+    pub fn found(&self) -> usize {
+        self.found
+    }
+}
+
+impl fmt::Display for LengthError {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            fmt,
+            "expected {} elements, found {}",
+            self.expected, self.found
+        )
+    }
+}
+
+impl Error for LengthError {}
+
+/// Tuples whose elements all have the same type `T`.
+///
+/// This bridges between tuples and arrays, so that homogeneous tuples can use
+/// array and slice APIs.
+pub trait HomogeneousTuple<T>: seal::Sealed + Sized {
+    /// Array type with the same length as the tuple, ie `[T; N]`.
+    type Array: AsRef<[T]> + AsMut<[T]> + IntoIterator<Item = T>;
+    /// Tuple of the same length with elements of type `U`.
+    type Mapped<U>: HomogeneousTuple<U>;
+
+    /// Convert the tuple into an array.
+    /// ```rust
+    /// # use tuplestructops::HomogeneousTuple;
+    /// let arr = (1, 2, 3).into_array();
+    /// assert_eq!(arr, [1, 2, 3]);
+    /// assert_eq!(arr.iter().max(), Some(&3));
+    /// ```
+    fn into_array(self) -> Self::Array;
+
+    /// Convert an array into a tuple.
+    /// ```rust
+    /// # use tuplestructops::HomogeneousTuple;
+    /// let tuple: (_, _, _) = HomogeneousTuple::from_array(['a', 'b', 'c']);
+    /// assert_eq!(tuple, ('a', 'b', 'c'));
+    /// ```
+    fn from_array(array: Self::Array) -> Self;
+
+    /// Construct a tuple with every element a clone of `value`. See also
+    /// [`repeat`] to specify the length explicitly.
+    /// ```rust
+    /// # use tuplestructops::HomogeneousTuple;
+    /// let tuple: (_, _, _) = HomogeneousTuple::repeat('x');
+    /// assert_eq!(tuple, ('x', 'x', 'x'));
+    /// ```
+    fn repeat(value: T) -> Self
+    where
+        T: Clone;
+
+    /// Construct a tuple by calling `f` with the index of each element, from
+    /// left to right. See also [`from_fn`] to specify the length explicitly.
+    /// ```rust
+    /// # use tuplestructops::HomogeneousTuple;
+    /// let tuple: (_, _, _) = HomogeneousTuple::from_fn(|i| i * 10);
+    /// assert_eq!(tuple, (0, 10, 20));
+    /// ```
+    fn from_fn<F>(f: F) -> Self
+    where
+        F: FnMut(usize) -> T;
+
+    /// Convert a slice into a tuple by cloning its elements, failing if it is
+    /// not the same length as the tuple.
+    /// ```rust
+    /// # use tuplestructops::HomogeneousTuple;
+    /// let row: Result<(_, _, _), _> = HomogeneousTuple::try_from_slice(&[1, 2, 3]);
+    /// assert_eq!(row, Ok((1, 2, 3)));
+    ///
+    /// let row: Result<(i32, i32, i32), _> = HomogeneousTuple::try_from_slice(&[1, 2]);
+    /// assert_eq!(row.unwrap_err().to_string(), "expected 3 elements, found 2");
+    /// ```
+    fn try_from_slice(slice: &[T]) -> Result<Self, LengthError>
+    where
+        T: Clone;
+
+    /// Convert a `Vec` into a tuple, failing if it is not the same length as
+    /// the tuple.
+    /// ```rust
+    /// # use tuplestructops::HomogeneousTuple;
+    /// let row: (String, String) = HomogeneousTuple::try_from_vec(vec!["a".into(), "b".into()]).unwrap();
+    /// assert_eq!(row, ("a".to_string(), "b".to_string()));
+    /// ```
+    fn try_from_vec(vec: Vec<T>) -> Result<Self, LengthError>;
+
+    /// Iterate over references to the elements.
+    /// ```rust
+    /// # use tuplestructops::HomogeneousTuple;
+    /// let total: f32 = (1.0, 2.0, 3.5).iter().sum();
+    /// assert_eq!(total, 6.5);
+    /// ```
+    fn iter<'a>(&'a self) -> impl DoubleEndedIterator<Item = &'a T> + ExactSizeIterator
+    where
+        T: 'a;
+
+    /// Iterate over mutable references to the elements.
+    /// ```rust
+    /// # use tuplestructops::HomogeneousTuple;
+    /// let mut tuple = (1, 2, 3);
+    /// tuple.iter_mut().for_each(|v| *v *= 10);
+    /// assert_eq!(tuple, (10, 20, 30));
+    /// ```
+    fn iter_mut<'a>(&'a mut self) -> impl DoubleEndedIterator<Item = &'a mut T> + ExactSizeIterator
+    where
+        T: 'a;
+
+    /// Iterate over the elements by value.
+    ///
+    /// Tuples can't implement `IntoIterator` directly, so this goes via
+    /// [`into_array`](Self::into_array).
+    /// ```rust
+    /// # use tuplestructops::HomogeneousTuple;
+    /// let words: Vec<String> = ("a".to_string(), "b".to_string()).into_iter().collect();
+    /// assert_eq!(words, ["a", "b"]);
+    /// ```
+    fn into_iter(self) -> <Self::Array as IntoIterator>::IntoIter {
+        self.into_array().into_iter()
+    }
+
+    /// Map each element with a closure, from left to right.
+    /// ```rust
+    /// # use tuplestructops::HomogeneousTuple;
+    /// assert_eq!((1, 2, 3).map(|v| v * 2), (2, 4, 6));
+    /// assert_eq!(("a", "bc").map(str::len), (1, 2));
+    /// ```
+    fn map<U, F>(self, f: F) -> Self::Mapped<U>
+    where
+        F: FnMut(T) -> U;
+
+    /// Running fold: compute the accumulated value after each element,
+    /// starting with `init`, and return them all as a tuple.
+    /// ```rust
+    /// # use tuplestructops::HomogeneousTuple;
+    /// assert_eq!((1, 2, 3, 4).scan(0, |acc, v| acc + v), (1, 3, 6, 10));
+    /// ```
+    fn scan<A, F>(self, init: A, mut f: F) -> Self::Mapped<A>
+    where
+        A: Clone,
+        F: FnMut(&A, T) -> A,
+    {
+        let mut acc = init;
+
+        self.map(|v| {
+            acc = f(&acc, v);
+            acc.clone()
+        })
+    }
+
+    /// Fold the elements into an accumulator, starting with `init`, from left
+    /// to right.
+    /// ```rust
+    /// # use tuplestructops::HomogeneousTuple;
+    /// let s = ("a", "b", "c").fold(String::new(), |acc, v| acc + v);
+    /// assert_eq!(s, "abc");
+    /// ```
+    fn fold<A, F>(self, init: A, f: F) -> A
+    where
+        F: FnMut(A, T) -> A,
+    {
+        self.into_iter().fold(init, f)
+    }
+
+    /// Reduce the elements to one by repeatedly applying `f`, from left to
+    /// right. Returns `None` for the unit tuple.
+    /// ```rust
+    /// # use tuplestructops::HomogeneousTuple;
+    /// assert_eq!((3, 7, 5).reduce(i32::max), Some(7));
+    /// assert_eq!(HomogeneousTuple::<i32>::reduce((), i32::max), None);
+    /// ```
+    fn reduce<F>(self, f: F) -> Option<T>
+    where
+        F: FnMut(T, T) -> T,
+    {
+        self.into_iter().reduce(f)
+    }
+
+    /// Sum the elements. The unit tuple sums to zero.
+    /// ```rust
+    /// # use tuplestructops::HomogeneousTuple;
+    /// assert_eq!((1.5, 2.0, 3.0).sum(), 6.5);
+    /// ```
+    fn sum(self) -> T
+    where
+        T: Sum,
+    {
+        self.into_iter().sum()
+    }
+
+    /// Multiply the elements. The unit tuple has a product of one.
+    /// ```rust
+    /// # use tuplestructops::HomogeneousTuple;
+    /// assert_eq!((2, 3, 4).product(), 24);
+    /// ```
+    fn product(self) -> T
+    where
+        T: Product,
+    {
+        self.into_iter().product()
+    }
+
+    /// Dot product with another tuple of the same shape. The unit tuple has a
+    /// dot product of zero.
+    /// ```rust
+    /// # use tuplestructops::HomogeneousTuple;
+    /// assert_eq!((1, 2, 3).dot((4, 5, 6)), 32);
+    /// ```
+    fn dot(self, other: Self) -> T
+    where
+        T: Mul<Output = T> + Sum,
+    {
+        self.into_iter()
+            .zip(other.into_iter())
+            .map(|(a, b)| a * b)
+            .sum()
+    }
+
+    /// Squared Euclidean length, ie the dot product with itself.
+    /// ```rust
+    /// # use tuplestructops::HomogeneousTuple;
+    /// assert_eq!((3, 4).length_squared(), 25);
+    /// ```
+    fn length_squared(self) -> T
+    where
+        T: Clone + Mul<Output = T> + Sum,
+    {
+        self.into_iter().map(|v| v.clone() * v).sum()
+    }
+
+    /// Euclidean length of a tuple of floats.
+    /// ```rust
+    /// # use tuplestructops::HomogeneousTuple;
+    /// assert_eq!((3.0, 4.0).length(), 5.0);
+    /// assert_eq!((1f32, 1f32, 1f32, 1f32).length(), 2.0);
+    /// ```
+    fn length(self) -> T
+    where
+        T: Float,
+    {
+        self.length_squared().sqrt()
+    }
+
+    /// Linear interpolation between `self` (at `t == 0`) and `other` (at
+    /// `t == 1`). `t` isn't clamped, so values outside of `0..=1` extrapolate.
+    /// ```rust
+    /// # use tuplestructops::HomogeneousTuple;
+    /// assert_eq!((0.0, 10.0).lerp((10.0, 20.0), 0.25), (2.5, 12.5));
+    /// ```
+    fn lerp(self, other: Self, t: T) -> Self
+    where
+        T: Float,
+    {
+        let mut out = self;
+        for (a, b) in out.iter_mut().zip(other.into_iter()) {
+            *a = *a + (b - *a) * t;
+        }
+        out
+    }
+
+    /// Arithmetic mean of the elements, computed as `f64`. Any type which
+    /// converts losslessly into `f64` can be used, including the smaller
+    /// integer types. Returns `None` for the unit tuple.
+    /// ```rust
+    /// # use tuplestructops::HomogeneousTuple;
+    /// assert_eq!((1u8, 2, 6).mean(), Some(3.0));
+    /// ```
+    fn mean(self) -> Option<f64>
+    where
+        T: Into<f64>,
+    {
+        let len = self.iter().len();
+        (len != 0).then(|| self.into_iter().map(Into::into).sum::<f64>() / len as f64)
+    }
+
+    /// Population variance of the elements, computed as `f64`. Returns `None`
+    /// for the unit tuple.
+    /// ```rust
+    /// # use tuplestructops::HomogeneousTuple;
+    /// assert_eq!((2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0).variance(), Some(4.0));
+    /// ```
+    fn variance(self) -> Option<f64>
+    where
+        T: Into<f64>,
+    {
+        let values = self.map(Into::<f64>::into);
+        let len = values.iter().len() as f64;
+        let mean = values.iter().sum::<f64>() / len;
+
+        (len != 0.0).then(|| values.iter().map(|v| (v - mean) * (v - mean)).sum::<f64>() / len)
+    }
+
+    /// Population standard deviation of the elements, computed as `f64`.
+    /// Returns `None` for the unit tuple.
+    /// ```rust
+    /// # use tuplestructops::HomogeneousTuple;
+    /// assert_eq!((2, 4, 4, 4, 5, 5, 7, 9).stddev(), Some(2.0));
+    /// ```
+    fn stddev(self) -> Option<f64>
+    where
+        T: Into<f64>,
+    {
+        self.variance().map(f64::sqrt)
+    }
+
+    /// Minimum element and its index. If several elements are equally minimum,
+    /// the first is returned. Returns `None` for the unit tuple.
+    ///
+    /// (This isn't called `min` since that would be ambiguous with
+    /// [`Ord::min`] for tuples.)
+    /// ```rust
+    /// # use tuplestructops::HomogeneousTuple;
+    /// assert_eq!((3, 1, 2, 1).min_elem(), Some((1, 1)));
+    /// ```
+    fn min_elem(self) -> Option<(usize, T)>
+    where
+        T: Ord,
+    {
+        self.into_iter()
+            .enumerate()
+            .min_by(|(_, a), (_, b)| a.cmp(b))
+    }
+
+    /// Maximum element and its index. If several elements are equally maximum,
+    /// the last is returned. Returns `None` for the unit tuple.
+    /// ```rust
+    /// # use tuplestructops::HomogeneousTuple;
+    /// assert_eq!((3, 1, 3, 2).max_elem(), Some((2, 3)));
+    /// ```
+    fn max_elem(self) -> Option<(usize, T)>
+    where
+        T: Ord,
+    {
+        self.into_iter()
+            .enumerate()
+            .max_by(|(_, a), (_, b)| a.cmp(b))
+    }
+
+    /// Element with the minimum key and its index. If several elements are
+    /// equally minimum, the first is returned. Returns `None` for the unit
+    /// tuple.
+    /// ```rust
+    /// # use tuplestructops::HomogeneousTuple;
+    /// assert_eq!((-3, 1, -2).min_elem_by_key(|v: &i32| v.abs()), Some((1, 1)));
+    /// ```
+    fn min_elem_by_key<K, F>(self, mut f: F) -> Option<(usize, T)>
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        self.into_iter().enumerate().min_by_key(|(_, v)| f(v))
+    }
+
+    /// Element with the maximum key and its index. If several elements are
+    /// equally maximum, the last is returned. Returns `None` for the unit
+    /// tuple.
+    /// ```rust
+    /// # use tuplestructops::HomogeneousTuple;
+    /// assert_eq!(("a", "ccc", "bb").max_elem_by_key(|s| s.len()), Some((1, "ccc")));
+    /// ```
+    fn max_elem_by_key<K, F>(self, mut f: F) -> Option<(usize, T)>
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        self.into_iter().enumerate().max_by_key(|(_, v)| f(v))
+    }
+
+    /// Return true if any element is equal to `x`.
+    /// ```rust
+    /// # use tuplestructops::HomogeneousTuple;
+    /// assert!(("a", "b").contains(&"b"));
+    /// assert!(!("a", "b").contains(&"c"));
+    /// ```
+    fn contains(&self, x: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.iter().any(|v| v == x)
+    }
+
+    /// Index of the first element matching `pred`, if any.
+    /// ```rust
+    /// # use tuplestructops::HomogeneousTuple;
+    /// assert_eq!((1, 4, 6).position(|v| v % 2 == 0), Some(1));
+    /// ```
+    fn position<P>(&self, pred: P) -> Option<usize>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.iter().position(pred)
+    }
+
+    /// Return true if every element matches `pred`. This is true for the unit
+    /// tuple.
+    /// ```rust
+    /// # use tuplestructops::HomogeneousTuple;
+    /// assert!((2, 4, 6).all(|v| v % 2 == 0));
+    /// ```
+    fn all<P>(&self, pred: P) -> bool
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.iter().all(pred)
+    }
+
+    /// Return true if any element matches `pred`. This is false for the unit
+    /// tuple.
+    /// ```rust
+    /// # use tuplestructops::HomogeneousTuple;
+    /// assert!((1, 4, 5).any(|v| v % 2 == 0));
+    /// ```
+    fn any<P>(&self, pred: P) -> bool
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.iter().any(pred)
+    }
+
+    /// Collect the elements matching `pred` into a `Vec`.
+    /// ```rust
+    /// # use tuplestructops::HomogeneousTuple;
+    /// assert_eq!((1, 2, 3, 4).filter(|v| v % 2 == 0), [2, 4]);
+    /// ```
+    fn filter<P>(self, pred: P) -> Vec<T>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.into_iter().filter(pred).collect()
+    }
+
+    /// Split the elements into those matching `pred` and those not, preserving
+    /// their order.
+    /// ```rust
+    /// # use tuplestructops::HomogeneousTuple;
+    /// let (even, odd) = (1, 2, 3, 4).partition(|v| v % 2 == 0);
+    /// assert_eq!(even, [2, 4]);
+    /// assert_eq!(odd, [1, 3]);
+    /// ```
+    fn partition<P>(self, pred: P) -> (Vec<T>, Vec<T>)
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.into_iter().partition(pred)
+    }
+}
+
+/// Marker type for a tuple length `N`. See [`TupleArity`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Arity<const N: usize>;
+
+/// Homogeneous tuple types of a given length.
+///
+/// This is implemented for [`Arity<N>`](Arity) for every supported tuple
+/// length `N`.
+pub trait TupleArity: seal::Sealed {
+    /// Tuple of `N` elements of type `T`.
+    type Tuple<T>: HomogeneousTuple<T>;
+}
+
+/// Homogeneous tuple type with `N` elements of type `T`.
+/// ```rust
+/// # use tuplestructops::TupleRepeat;
+/// let rgb: TupleRepeat<u8, 3> = (255, 128, 0);
+/// ```
+pub type TupleRepeat<T, const N: usize> = <Arity<N> as TupleArity>::Tuple<T>;
+
+/// Construct an `N` element tuple with every element a clone of `value`.
+/// ```rust
+/// # use tuplestructops::repeat;
+/// assert_eq!(repeat::<4, _>(0u8), (0, 0, 0, 0));
+/// ```
+pub fn repeat<const N: usize, T: Clone>(value: T) -> TupleRepeat<T, N>
+where
+    Arity<N>: TupleArity,
+{
+    HomogeneousTuple::repeat(value)
+}
+
+/// Construct an `N` element tuple by calling `f` with the index of each
+/// element, from left to right.
+/// ```rust
+/// # use tuplestructops::from_fn;
+/// assert_eq!(from_fn::<3, _>(|i| i.to_string()), ("0".to_string(), "1".to_string(), "2".to_string()));
+/// ```
+pub fn from_fn<const N: usize, T>(f: impl FnMut(usize) -> T) -> TupleRepeat<T, N>
+where
+    Arity<N>: TupleArity,
+{
+    HomogeneousTuple::from_fn(f)
+}
+
+macro_rules! impl_homogeneous {
+    ($n:literal) => {
+        seq!(J in 0..$n {
+            impl seal::Sealed for Arity<$n> {}
+
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl TupleArity for Arity<$n> {
+                type Tuple<T> = (#(T,)*);
+            }
+
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<T> HomogeneousTuple<T> for (#(T,)*) {
+                type Array = [T; $n];
+                type Mapped<U> = (#(U,)*);
+
+                fn into_array(self) -> [T; $n] {
+                    let (#(t~J,)*) = self;
+
+                    [#(t~J,)*]
+                }
+
+                #[allow(clippy::unused_unit)]
+                fn from_array(array: [T; $n]) -> Self {
+                    let [#(t~J,)*] = array;
+
+                    (#(t~J,)*)
+                }
+
+                fn repeat(value: T) -> Self
+                where
+                    T: Clone,
+                {
+                    Self::from_array(std::array::from_fn(|_| value.clone()))
+                }
+
+                fn from_fn<F>(f: F) -> Self
+                where
+                    F: FnMut(usize) -> T,
+                {
+                    Self::from_array(std::array::from_fn(f))
+                }
+
+                #[allow(clippy::unused_unit, unused_mut, unused_variables)]
+                fn map<U, F>(self, mut f: F) -> Self::Mapped<U>
+                where
+                    F: FnMut(T) -> U,
+                {
+                    let (#(t~J,)*) = self;
+
+                    (#(f(t~J),)*)
+                }
+
+                fn try_from_slice(slice: &[T]) -> Result<Self, LengthError>
+                where
+                    T: Clone,
+                {
+                    match <&[T; $n]>::try_from(slice) {
+                        Ok(array) => Ok(Self::from_array(array.clone())),
+                        Err(_) => Err(LengthError::new($n, slice.len())),
+                    }
+                }
+
+                fn try_from_vec(vec: Vec<T>) -> Result<Self, LengthError> {
+                    match <[T; $n]>::try_from(vec) {
+                        Ok(array) => Ok(Self::from_array(array)),
+                        Err(vec) => Err(LengthError::new($n, vec.len())),
+                    }
+                }
+
+                fn iter<'a>(&'a self) -> impl DoubleEndedIterator<Item = &'a T> + ExactSizeIterator
+                where
+                    T: 'a,
+                {
+                    [#(&self.J,)*].into_iter()
+                }
+
+                fn iter_mut<'a>(&'a mut self) -> impl DoubleEndedIterator<Item = &'a mut T> + ExactSizeIterator
+                where
+                    T: 'a,
+                {
+                    [#(&mut self.J,)*].into_iter()
+                }
+            }
+        });
+    };
+}
+
+for_each_tuple!(impl_homogeneous);
+
+/// Sorting of homogeneous tuples of up to 16 elements.
+///
+/// Each length has its own sorting network, so sorting is done with a fixed
+/// sequence of compare-and-swap operations without any allocation. The
+/// networks have the minimum known number of comparisons for their size
+/// (proven optimal up to 12 elements). Like [`slice::sort_unstable`], the sort
+/// is not stable.
+pub trait TupleSort<T>: HomogeneousTuple<T> {
+    /// Sort the elements with a comparison function.
+    /// ```rust
+    /// # use tuplestructops::TupleSort;
+    /// let mut tuple = (1, 5, 3, 2);
+    /// tuple.sort_by(|a, b| b.cmp(a));
+    /// assert_eq!(tuple, (5, 3, 2, 1));
+    /// ```
+    fn sort_by<F>(&mut self, compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering;
+
+    /// Sort the elements.
+    /// ```rust
+    /// # use tuplestructops::TupleSort;
+    /// let mut tuple = ("c", "a", "b");
+    /// tuple.sort();
+    /// assert_eq!(tuple, ("a", "b", "c"));
+    /// ```
+    fn sort(&mut self)
+    where
+        T: Ord,
+    {
+        self.sort_by(T::cmp)
+    }
+
+    /// Sort the elements by a key extraction function.
+    /// ```rust
+    /// # use tuplestructops::TupleSort;
+    /// let mut tuple = (-3, 1, -2);
+    /// tuple.sort_by_key(|v: &i32| v.abs());
+    /// assert_eq!(tuple, (1, -2, -3));
+    /// ```
+    fn sort_by_key<K, F>(&mut self, mut f: F)
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        self.sort_by(|a, b| f(a).cmp(&f(b)))
+    }
+}
+
+/// Implement `TupleSort` for an `N` tuple with a sorting network, given as a
+/// sequence of `[a b]` comparators (`a < b`). Each line of comparators is one
+/// parallel layer of the network.
+macro_rules! impl_sort {
+    ($n:literal; $([$a:tt $b:tt])*) => {
+        seq!(J in 0..$n {
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<T> TupleSort<T> for (#(T,)*) {
+                #[allow(unused_mut, unused_variables)]
+                fn sort_by<F>(&mut self, mut compare: F)
+                where
+                    F: FnMut(&T, &T) -> Ordering,
+                {
+                    $(
+                        if compare(&self.$b, &self.$a) == Ordering::Less {
+                            std::mem::swap(&mut self.$a, &mut self.$b);
+                        }
+                    )*
+                }
+            }
+        });
+    };
+}
+
+impl_sort!(0;);
+impl_sort!(1;);
+impl_sort!(2;
+    [0 1]
+);
+impl_sort!(3;
+    [0 1]
+    [0 2]
+    [1 2]
+);
+impl_sort!(4;
+    [0 1] [2 3]
+    [0 2] [1 3]
+    [1 2]
+);
+impl_sort!(5;
+    [0 3] [1 4]
+    [0 2] [1 3]
+    [0 1] [2 4]
+    [1 2] [3 4]
+    [2 3]
+);
+impl_sort!(6;
+    [0 5] [1 3] [2 4]
+    [1 2] [3 4]
+    [0 3] [2 5]
+    [0 1] [2 3] [4 5]
+    [1 2] [3 4]
+);
+impl_sort!(7;
+    [0 6] [2 3] [4 5]
+    [0 2] [1 4] [3 6]
+    [0 1] [2 5] [3 4]
+    [1 2] [4 6]
+    [2 3] [4 5]
+    [1 2] [3 4] [5 6]
+);
+impl_sort!(8;
+    [0 2] [1 3] [4 6] [5 7]
+    [0 4] [1 5] [2 6] [3 7]
+    [0 1] [2 3] [4 5] [6 7]
+    [2 4] [3 5]
+    [1 4] [3 6]
+    [1 2] [3 4] [5 6]
+);
+impl_sort!(9;
+    [0 3] [1 7] [2 5] [4 8]
+    [0 7] [2 4] [3 8] [5 6]
+    [0 2] [1 3] [4 5] [7 8]
+    [1 4] [3 6] [5 7]
+    [0 1] [2 4] [3 5] [6 8]
+    [2 3] [4 5] [6 7]
+    [1 2] [3 4] [5 6]
+);
+impl_sort!(10;
+    [0 8] [1 9] [2 7] [3 5] [4 6]
+    [0 2] [1 4] [5 8] [7 9]
+    [0 3] [2 4] [5 7] [6 9]
+    [0 1] [3 6] [8 9]
+    [1 5] [2 3] [4 8] [6 7]
+    [1 2] [3 5] [4 6] [7 8]
+    [2 3] [4 5] [6 7]
+    [3 4] [5 6]
+);
+impl_sort!(11;
+    [0 9] [1 6] [2 4] [3 7] [5 8]
+    [0 1] [3 5] [4 10] [6 9] [7 8]
+    [1 3] [2 5] [4 7] [8 10]
+    [0 4] [1 2] [3 7] [5 9] [6 8]
+    [0 1] [2 6] [4 5] [7 8] [9 10]
+    [2 4] [3 6] [5 7] [8 9]
+    [1 2] [3 4] [5 6] [7 8]
+    [2 3] [4 5] [6 7]
+);
+impl_sort!(12;
+    [0 8] [1 7] [2 6] [3 11] [4 10] [5 9]
+    [0 1] [2 5] [3 4] [6 9] [7 8] [10 11]
+    [0 2] [1 6] [5 10] [9 11]
+    [0 3] [1 2] [4 6] [5 7] [8 11] [9 10]
+    [1 4] [3 5] [6 8] [7 10]
+    [1 3] [2 5] [6 9] [8 10]
+    [2 3] [4 5] [6 7] [8 9]
+    [4 6] [5 7]
+    [3 4] [5 6] [7 8]
+);
+impl_sort!(13;
+    [0 12] [1 10] [2 9] [3 7] [5 11] [6 8]
+    [1 6] [2 3] [4 11] [7 9] [8 10]
+    [0 4] [1 2] [3 6] [7 8] [9 10] [11 12]
+    [4 6] [5 9] [8 11] [10 12]
+    [0 5] [3 8] [4 7] [6 11] [9 10]
+    [0 1] [2 5] [6 9] [7 8] [10 11]
+    [1 3] [2 4] [5 6] [9 10]
+    [1 2] [3 4] [5 7] [6 8]
+    [2 3] [4 5] [6 7] [8 9]
+    [3 4] [5 6]
+);
+impl_sort!(14;
+    [0 13] [1 12] [4 8] [5 6] [7 11] [9 10]
+    [0 5] [1 7] [2 9] [3 4] [6 13] [11 12]
+    [0 1] [2 3] [4 5] [6 8] [7 9] [10 11] [12 13]
+    [0 2] [1 3] [4 10] [5 11] [6 7] [8 9]
+    [1 2] [3 12] [4 6] [5 7] [8 10] [9 11]
+    [1 4] [2 6] [5 8] [7 10] [9 13]
+    [2 4] [3 6] [9 12] [11 13]
+    [3 5] [6 8] [7 9] [10 12]
+    [3 4] [5 6] [7 8] [9 10] [11 12]
+    [6 7] [8 9]
+);
+impl_sort!(15;
+    [0 13] [1 12] [3 14] [4 8] [5 6] [7 11] [9 10]
+    [0 5] [1 7] [2 9] [3 4] [6 13] [8 14] [11 12]
+    [0 1] [2 3] [4 5] [6 8] [7 9] [10 11] [12 13]
+    [0 2] [1 3] [4 10] [5 11] [6 7] [8 9] [12 14]
+    [1 2] [3 12] [4 6] [5 7] [8 10] [9 11] [13 14]
+    [1 4] [2 6] [5 8] [7 10] [9 13] [11 14]
+    [2 4] [3 6] [9 12] [11 13]
+    [3 5] [6 8] [7 9] [10 12]
+    [3 4] [5 6] [7 8] [9 10] [11 12]
+    [6 7] [8 9]
+);
+impl_sort!(16;
+    [0 13] [1 12] [2 15] [3 14] [4 8] [5 6] [7 11] [9 10]
+    [0 5] [1 7] [2 9] [3 4] [6 13] [8 14] [10 15] [11 12]
+    [0 1] [2 3] [4 5] [6 8] [7 9] [10 11] [12 13] [14 15]
+    [0 2] [1 3] [4 10] [5 11] [6 7] [8 9] [12 14] [13 15]
+    [1 2] [3 12] [4 6] [5 7] [8 10] [9 11] [13 14]
+    [1 4] [2 6] [5 8] [7 10] [9 13] [11 14]
+    [2 4] [3 6] [9 12] [11 13]
+    [3 5] [6 8] [7 9] [10 12]
+    [3 4] [5 6] [7 8] [9 10] [11 12]
+    [6 7] [8 9]
+);
@@ -0,0 +1,138 @@
+use super::*;
+
+/// Number of elements in a tuple.
+pub trait TupleLen: seal::Sealed {
+    /// Number of elements.
+    const LEN: usize;
+
+    /// Number of elements in the tuple.
+    /// ```rust
+    /// # use tuplestructops::TupleLen;
+    /// assert_eq!((1, 'a', "b").len(), 3);
+    /// assert_eq!(().len(), 0);
+    /// ```
+    fn len(&self) -> usize {
+        Self::LEN
+    }
+
+    /// True for the unit tuple.
+    fn is_empty(&self) -> bool {
+        Self::LEN == 0
+    }
+}
+
+/// Access the element at index `I` of a tuple, where `I` is a const generic
+/// parameter rather than a field name.
+pub trait TupleIdx<const I: usize>: seal::Sealed {
+    /// Type of element `I`, or a reference to it when indexing a tuple by
+    /// shared or mutable reference.
+    type Output;
+
+    /// Return element `I`.
+    /// ```rust
+    /// # use tuplestructops::TupleIdx;
+    /// let mut t = (1, 'a', String::from("b"));
+    /// assert_eq!(TupleIdx::<1>::idx(&t), &'a');
+    /// TupleIdx::<2>::idx(&mut t).push('c');
+    /// assert_eq!(TupleIdx::<2>::idx(t), "bc");
+    /// ```
+    fn idx(self) -> Self::Output;
+}
+
+/// Method-call syntax for [`TupleIdx`], with the index as a turbofish.
+pub trait TupleGet: seal::Sealed + Sized {
+    /// Reference to element `I`.
+    /// ```rust
+    /// # use tuplestructops::TupleGet;
+    /// let t = (1, 'a', String::from("b"));
+    /// assert_eq!(t.get::<1>(), &'a');
+    /// assert_eq!(t.get::<2>().len(), 1);
+    /// ```
+    fn get<'a, const I: usize>(&'a self) -> <&'a Self as TupleIdx<I>>::Output
+    where
+        &'a Self: TupleIdx<I>,
+    {
+        TupleIdx::<I>::idx(self)
+    }
+
+    /// Mutable reference to element `I`.
+    /// ```rust
+    /// # use tuplestructops::TupleGet;
+    /// let mut t = (1, 'a', String::from("b"));
+    /// *t.get_mut::<0>() += 1;
+    /// t.get_mut::<2>().push('c');
+    /// assert_eq!(t, (2, 'a', String::from("bc")));
+    /// ```
+    fn get_mut<'a, const I: usize>(&'a mut self) -> <&'a mut Self as TupleIdx<I>>::Output
+    where
+        &'a mut Self: TupleIdx<I>,
+    {
+        TupleIdx::<I>::idx(self)
+    }
+
+    /// Move out element `I`, consuming the tuple.
+    /// ```rust
+    /// # use tuplestructops::TupleGet;
+    /// let t = (1, 'a', String::from("b"));
+    /// let s: String = t.idx_of::<2>();
+    /// assert_eq!(s, "b");
+    /// ```
+    fn idx_of<const I: usize>(self) -> <Self as TupleIdx<I>>::Output
+    where
+        Self: TupleIdx<I>,
+    {
+        TupleIdx::<I>::idx(self)
+    }
+}
+
+macro_rules! impl_idx {
+    ($n:literal) => {
+        seq!(J in 0..$n {
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<#(T~J,)*> TupleLen for (#(T~J,)*) {
+                const LEN: usize = $n;
+            }
+
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<#(T~J,)*> TupleGet for (#(T~J,)*) {}
+        });
+
+        seq!(I in 0..$n {
+            #(
+                seq!(J in 0..$n {
+                    // Index by value
+                    #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+                    impl<#(T~J,)*> TupleIdx<I> for (#(T~J,)*) {
+                        type Output = T~I;
+
+                        fn idx(self) -> Self::Output {
+                            self.I
+                        }
+                    }
+
+                    // Index by reference
+                    #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+                    impl<'a, #(T~J,)*> TupleIdx<I> for &'a (#(T~J,)*) {
+                        type Output = &'a T~I;
+
+                        fn idx(self) -> Self::Output {
+                            &self.I
+                        }
+                    }
+
+                    // Index by mutable reference
+                    #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+                    impl<'a, #(T~J,)*> TupleIdx<I> for &'a mut (#(T~J,)*) {
+                        type Output = &'a mut T~I;
+
+                        fn idx(self) -> Self::Output {
+                            &mut self.I
+                        }
+                    }
+                });
+            )*
+        });
+    };
+}
+
+for_each_tuple!(impl_idx);
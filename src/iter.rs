@@ -0,0 +1,218 @@
+//! Iterator adapters for tuples.
+use super::*;
+
+/// Zip a tuple of iterables into an iterator of tuples.
+pub trait TupleZipIters: seal::Sealed {
+    /// Tuple of iterators.
+    type Iters;
+
+    /// Zip the elements together, yielding a tuple of items until the shortest
+    /// iterator ends. This is a variadic [`Iterator::zip`].
+    /// ```rust
+    /// # use tuplestructops::TupleZipIters;
+    /// let zipped: Vec<_> = (1..4, "abc".chars(), vec![true, false]).zip_iters().collect();
+    /// assert_eq!(zipped, [(1, 'a', true), (2, 'b', false)]);
+    /// ```
+    /// This isn't implemented for the unit tuple.
+    fn zip_iters(self) -> ZipIters<Self::Iters>;
+}
+
+/// Iterator over tuples of items from a tuple of iterators. See
+/// [`TupleZipIters::zip_iters`].
+#[derive(Debug, Clone)]
+pub struct ZipIters<I>(I);
+
+/// Tuple of collections which can be built by unzipping an iterator of tuples.
+/// This isn't implemented for the unit tuple.
+pub trait TupleUnzipInto<Item>: seal::Sealed + Sized {
+    /// Build each collection from the corresponding element of each item.
+    fn unzip_from<I: IntoIterator<Item = Item>>(iter: I) -> Self;
+}
+
+/// Iterator extension to unzip an iterator of tuples into a tuple of
+/// collections.
+pub trait IteratorUnzipN: Iterator + Sized {
+    /// Unzip into a tuple of collections, each of which must implement
+    /// `Default` and `Extend`. This generalizes [`Iterator::unzip`] to all
+    /// tuple sizes.
+    /// ```rust
+    /// # use std::collections::BTreeSet;
+    /// # use tuplestructops::IteratorUnzipN;
+    /// let rows = vec![(1, 'a', "x"), (2, 'b', "y"), (1, 'c', "z")];
+    /// let (nums, chars, strs): (BTreeSet<_>, String, Vec<_>) = rows.into_iter().unzip_n();
+    /// assert_eq!(nums.into_iter().collect::<Vec<_>>(), [1, 2]);
+    /// assert_eq!(chars, "abc");
+    /// assert_eq!(strs, ["x", "y", "z"]);
+    /// ```
+    fn unzip_n<C: TupleUnzipInto<Self::Item>>(self) -> C {
+        C::unzip_from(self)
+    }
+}
+
+impl<I: Iterator> IteratorUnzipN for I {}
+
+/// Cartesian product of a tuple of iterables.
+pub trait TupleCartesian: seal::Sealed {
+    /// Tuple of iterators.
+    type Iters;
+    /// Tuple of items.
+    type Item;
+
+    /// Yield every combination of items as a tuple, with the last element
+    /// varying fastest. The iterators are cloned to restart them, and the items
+    /// are cloned into each combination.
+    /// ```rust
+    /// # use tuplestructops::TupleCartesian;
+    /// let combos: Vec<_> = (0..2, ['a', 'b'], [true]).cartesian().collect();
+    /// assert_eq!(
+    ///     combos,
+    ///     [(0, 'a', true), (0, 'b', true), (1, 'a', true), (1, 'b', true)]
+    /// );
+    /// ```
+    /// If any of the iterators is empty then so is the product.
+    fn cartesian(self) -> Cartesian<Self::Iters, Self::Item>;
+}
+
+/// Iterator over the cartesian product of a tuple of iterators. See
+/// [`TupleCartesian::cartesian`].
+#[derive(Debug, Clone)]
+pub struct Cartesian<I, T> {
+    orig: I,
+    iters: I,
+    cur: Option<T>,
+    done: bool,
+}
+
+// Advance the odometer of iterators, from the last to the first. Returns false
+// once the first iterator is exhausted.
+macro_rules! cartesian_advance {
+    ($orig:expr, $iters:expr, $cur:expr; [$first:tt $($rest:tt)*] [$($rev:tt)*]) => {
+        cartesian_advance!($orig, $iters, $cur; [$($rest)*] [$first $($rev)*])
+    };
+    ($orig:expr, $iters:expr, $cur:expr; [] [$($rev:tt)*]) => {
+        'advance: {
+            $(
+                if let Some(v) = $iters.$rev.next() {
+                    $cur.$rev = v;
+                    break 'advance true;
+                }
+                $iters.$rev = $orig.$rev.clone();
+                match $iters.$rev.next() {
+                    Some(v) => $cur.$rev = v,
+                    None => break 'advance false,
+                }
+            )*
+            false
+        }
+    };
+}
+
+macro_rules! impl_zip_iters {
+    (0) => {};
+    ($n:literal) => {
+        seq!(J in 0..$n {
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<#(I~J: IntoIterator,)*> TupleZipIters for (#(I~J,)*) {
+                type Iters = (#(I~J::IntoIter,)*);
+
+                #[allow(non_snake_case)]
+                fn zip_iters(self) -> ZipIters<Self::Iters> {
+                    let (#(I~J,)*) = self;
+
+                    ZipIters((#(I~J.into_iter(),)*))
+                }
+            }
+
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<#(I~J: Iterator,)*> Iterator for ZipIters<(#(I~J,)*)> {
+                type Item = (#(I~J::Item,)*);
+
+                fn next(&mut self) -> Option<Self::Item> {
+                    Some((#(self.0.J.next()?,)*))
+                }
+
+                fn size_hint(&self) -> (usize, Option<usize>) {
+                    let hints = [#(self.0.J.size_hint(),)*];
+                    let lower = hints.iter().map(|(lower, _)| *lower).min().unwrap();
+                    let upper = hints.iter().filter_map(|(_, upper)| *upper).min();
+
+                    (lower, upper)
+                }
+            }
+
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<#(C~J: Default + Extend<T~J>, T~J,)*> TupleUnzipInto<(#(T~J,)*)> for (#(C~J,)*) {
+                fn unzip_from<I: IntoIterator<Item = (#(T~J,)*)>>(iter: I) -> Self {
+                    let mut out = (#(C~J::default(),)*);
+
+                    for (#(t~J,)*) in iter {
+                        #(out.J.extend(Some(t~J));)*
+                    }
+                    out
+                }
+            }
+        });
+    };
+}
+
+for_each_tuple!(impl_zip_iters);
+
+macro_rules! impl_cartesian {
+    ($n:literal) => {
+        seq!(J in 0..$n {
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<#(I~J,)*> TupleCartesian for (#(I~J,)*)
+            where
+                #(I~J: IntoIterator, I~J::IntoIter: Clone, I~J::Item: Clone,)*
+            {
+                type Iters = (#(I~J::IntoIter,)*);
+                type Item = (#(I~J::Item,)*);
+
+                #[allow(clippy::unused_unit, non_snake_case)]
+                fn cartesian(self) -> Cartesian<Self::Iters, Self::Item> {
+                    let (#(I~J,)*) = self;
+                    let iters = (#(I~J.into_iter(),)*);
+
+                    Cartesian {
+                        orig: iters.clone(),
+                        iters,
+                        cur: None,
+                        done: false,
+                    }
+                }
+            }
+
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<#(I~J,)*> Iterator for Cartesian<(#(I~J,)*), (#(I~J::Item,)*)>
+            where
+                #(I~J: Iterator + Clone, I~J::Item: Clone,)*
+            {
+                type Item = (#(I~J::Item,)*);
+
+                #[allow(clippy::unused_unit, unused_labels, unused_variables)]
+                fn next(&mut self) -> Option<Self::Item> {
+                    if self.done {
+                        return None;
+                    }
+
+                    let more = match &mut self.cur {
+                        None => {
+                            self.cur = (#(self.iters.J.next(),)*).transpose();
+                            self.cur.is_some()
+                        }
+                        Some(cur) => cartesian_advance!(self.orig, self.iters, cur; [#(J)*] []),
+                    };
+
+                    if more {
+                        self.cur.clone()
+                    } else {
+                        self.done = true;
+                        None
+                    }
+                }
+            }
+        });
+    };
+}
+
+for_each_tuple!(impl_cartesian);
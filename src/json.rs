@@ -0,0 +1,129 @@
+//! Conversions between tuples and [`serde_json`] arrays.
+//!
+//! [`TupleToJson`] converts each element of a tuple to a JSON value and
+//! collects them into a `Value::Array`, and [`TupleFromJson`] converts back,
+//! reporting which element failed. This is handy for quick interchange
+//! without defining wrapper types:
+//! ```rust
+//! # use serde_json::json;
+//! # use tuplestructops::json::{TupleFromJson, TupleToJson};
+//! let value = (1, "a", [true, false]).to_json_array().unwrap();
+//! assert_eq!(value, json!([1, "a", [true, false]]));
+//!
+//! let t = <(u8, String)>::from_json_array(json!([1, "b"])).unwrap();
+//! assert_eq!(t, (1, "b".to_string()));
+//!
+//! let err = <(u8, String)>::from_json_array(json!([1, 2])).unwrap_err();
+//! assert_eq!(err.to_string(), "element 1: invalid type: integer `2`, expected a string");
+//! ```
+use std::error::Error;
+use std::fmt;
+
+use ::serde::de::DeserializeOwned;
+use ::serde::Serialize;
+use ::serde_json::Value;
+
+use super::*;
+
+/// Error from converting between a tuple and a JSON array.
+#[derive(Debug)]
+pub enum JsonArrayError {
+    /// The value wasn't an array.
+    NotArray,
+    /// The array had the wrong number of elements.
+    Count {
+        /// Number of elements in the tuple.
+        expected: usize,
+        /// Number of elements in the array.
+        found: usize,
+    },
+    /// An element failed to convert.
+    Elem {
+        /// Index of the element.
+        index: usize,
+        /// The underlying error.
+        error: serde_json::Error,
+    },
+}
+
+impl JsonArrayError {
+    /// Index of the element which failed to convert, if any.
+    pub fn index(&self) -> Option<usize> {
+        match *self {
+            JsonArrayError::Elem { index, .. } => Some(index),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for JsonArrayError {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            JsonArrayError::NotArray => write!(fmt, "expected a JSON array"),
+            JsonArrayError::Count { expected, found } => {
+                write!(fmt, "expected {} elements, found {}", expected, found)
+            }
+            JsonArrayError::Elem { index, error } => write!(fmt, "element {}: {}", index, error),
+        }
+    }
+}
+
+impl Error for JsonArrayError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            JsonArrayError::Elem { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+/// Conversion of tuples into JSON arrays.
+pub trait TupleToJson: seal::Sealed {
+    /// Convert each element to a JSON value with [`serde_json::to_value`],
+    /// and collect them into a `Value::Array`.
+    fn to_json_array(&self) -> Result<Value, JsonArrayError>;
+}
+
+/// Conversion of JSON arrays into tuples.
+pub trait TupleFromJson: seal::Sealed + Sized {
+    /// Convert each element of a JSON array into the corresponding element of
+    /// the tuple with [`serde_json::from_value`]. The array must have exactly
+    /// as many elements as the tuple.
+    fn from_json_array(value: Value) -> Result<Self, JsonArrayError>;
+}
+
+macro_rules! impl_json {
+    ($n:literal) => {
+        seq!(J in 0..$n {
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<#(T~J: Serialize,)*> TupleToJson for (#(T~J,)*) {
+                fn to_json_array(&self) -> Result<Value, JsonArrayError> {
+                    Ok(Value::Array(vec![#(
+                        serde_json::to_value(&self.J)
+                            .map_err(|error| JsonArrayError::Elem { index: J, error })?,
+                    )*]))
+                }
+            }
+
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<#(T~J: DeserializeOwned,)*> TupleFromJson for (#(T~J,)*) {
+                #[allow(unused_mut, unused_variables, clippy::unused_unit)]
+                fn from_json_array(value: Value) -> Result<Self, JsonArrayError> {
+                    let Value::Array(elems) = value else {
+                        return Err(JsonArrayError::NotArray);
+                    };
+                    if elems.len() != $n {
+                        return Err(JsonArrayError::Count { expected: $n, found: elems.len() });
+                    }
+                    let mut elems = elems.into_iter();
+                    Ok((#(
+                        serde_json::from_value(elems.next().unwrap())
+                            .map_err(|error| JsonArrayError::Elem { index: J, error })?,
+                    )*))
+                }
+            }
+        });
+    };
+}
+
+for_each_tuple!(impl_json);
@@ -1,6 +1,134 @@
 //! # Structural operations for tuples
 //!
-//! This crate implements splitting and joining tuples.
+//! This crate implements splitting and joining tuples, along with other
+//! structural operations:
+//! - [`TupleLen`] and [`TupleIdx`] - length and const-generic indexing
+//! - [`TupleGet`] - `t.get::<N>()`, `t.get_mut::<N>()` and `t.idx_of::<N>()`
+//!   method syntax for indexing
+//! - [`TupleLayout`] and [`layout_of`] - offset, size and alignment of each
+//!   element
+//! - [`TupleTypeIds`] and [`TupleTypeNames`] - `TypeId`s and names of the
+//!   element types
+//! - [`TupleIdxDyn`] - typed access to an element selected by a runtime index
+//! - [`TupleForEachDyn`] - visit each element as `&dyn Any`
+//! - [`TupleOps`] - conversion between structs and tuples of their fields, with
+//!   `#[derive(TupleOps)]` (`derive` feature)
+//! - [`TupleLike`] - newtype wrappers around tuples, with `#[derive(TupleLike)]`
+//!   (`derive` feature)
+//! - [`repr_c_tuple!`] - define `#[repr(C)]` structs for passing tuples over FFI
+//! - [`impl_for_tuples!`] - implement a trait for tuples by combining the
+//!   results from each element
+//! - [`TupleAsDyn`] and [`impl_tuple_dyn!`] - view each element as a user
+//!   trait object
+//! - [`Tup`] - wrapper with the main operations as inherent methods, and
+//!   operators for joining and calling
+//! - [`TupleDebug`] - `Debug` formatting for tuples of any size, including a
+//!   multi-line format with element indices
+//! - [`TupleDisplay`] - display the elements of a tuple with a separator
+//! - [`assert_tuple_eq!`] - assert tuples are equal, reporting each differing
+//!   element
+//! - [`TupleDefault`] and [`default_tuple`] - `Default` for tuples of any size
+//! - [`wide::Ordered`] - comparison and hashing for tuples of any size
+//! - [`TupleClone`] - clone tuples of any size, or tuples of references
+//! - [`TupleConvert`] - elementwise `Into` conversion
+//! - [`TupleTryConvert`] - elementwise `TryInto` conversion
+//! - [`TupleFromStr`] and [`parse_tuple`] - parse a delimited string into a tuple
+//! - [`TupleAnyVec`] - conversion to and from `Vec<Box<dyn Any>>`
+//! - [`DynTuple`] - a tuple whose length and element types are only known at
+//!   runtime
+//! - [`TupleIntoCons`] and [`ConsIntoTuple`] - conversion to and from nested
+//!   cons lists of pairs
+//! - [`TupleTryMap`] - fallible heterogeneous map with a [`TryMapper`]
+//! - [`TupleEnumerate`] - pair each element with its index
+//! - [`TupleZipWith`] - combine two tuples elementwise with a [`BiMapper`]
+//! - [`TupleFoldWith`] - fold over two tuples in lockstep with a [`BiFolder`]
+//! - [`HomogeneousTuple`] - conversion between homogeneous tuples and arrays,
+//!   slices and `Vec`s, iteration, closure-based operations, and vector math
+//!   and statistics such as dot products, lengths, interpolation and means
+//! - [`repeat`], [`from_fn`] and [`TupleRepeat`] - construct and name
+//!   homogeneous tuples
+//! - [`TupleSort`] - sorting networks for homogeneous tuples
+//! - [`ArrayOfTuples`] and [`TupleOfArrays`] - conversion between arrays of
+//!   tuples and tuples of arrays
+//! - [`TupleAdd`], [`TupleSub`], [`TupleMul`] and [`TupleDiv`] - elementwise
+//!   arithmetic
+//! - [`TupleChecked`], [`TupleSaturating`] and [`TupleWrapping`] - elementwise
+//!   overflow-aware integer arithmetic
+//! - [`TupleMinMax`] - elementwise minimum, maximum and clamping
+//! - [`TupleTranspose`] - tuple of `Option`s into an `Option` of a tuple
+//! - [`TupleTryCollect`] - tuple of `Result`s into a `Result` of a tuple
+//! - [`TupleValidate`] - like [`TupleTryCollect`], but accumulating all errors
+//! - [`unzip_option`] and [`unzip_result`] - distribute an `Option` or `Result`
+//!   over a tuple
+//! - [`TupleTakeAll`] and [`TupleUnwrapOrDefault`] - helpers for tuples of
+//!   `Option`s
+//! - [`TupleZipIters`] - zip a tuple of iterators into an iterator of tuples
+//! - [`IteratorUnzipN`] - unzip an iterator of tuples into a tuple of
+//!   collections
+//! - [`TupleCartesian`] - cartesian product of a tuple of iterators
+//! - `TupleMapAsync` - asynchronous heterogeneous map (`futures` feature)
+//! - `TupleJoinAll` - join a tuple of futures (`futures` feature)
+//! - `TupleTryJoin` - join a tuple of fallible futures (`futures` feature)
+//! - `TupleRace` - race a tuple of futures (`futures` feature)
+//! - `TupleZipStreams` - zip a tuple of streams (`futures` feature)
+//! - `TupleSpawnAll` and `TupleJoinHandles` - spawn a tuple of futures as tokio
+//!   tasks and await them (`tokio` feature)
+//! - `PodTuple` - zero-copy casts of homogeneous tuples (`bytemuck` feature)
+//! - `SerdeTuple` and `SerdeTupleMap` - serialization of tuples of any size,
+//!   as sequences or as maps keyed by index (`serde` feature)
+//! - `TupleFromRecord` and `TupleToRecord` - conversions to and from csv
+//!   `StringRecord`s (`csv` feature)
+//! - `TupleToJson` and `TupleFromJson` - conversions to and from `serde_json`
+//!   arrays (`serde_json` feature)
+//! - `TupleJsonSchema` - JSON Schemas for serialized tuples of any size
+//!   (`schemars` feature)
+//! - `AsTuple` - zero-copy archiving of tuples of any size (`rkyv` feature)
+//! - `TupleStrategy` - property testing strategies for tuples of any size
+//!   (`proptest` feature)
+//! - `quickcheck::ArbitraryTuple` - quickcheck `Arbitrary` for tuples of any size
+//!   (`quickcheck` feature)
+//! - `arbitrary::ArbitraryTuple` - fuzzing input of tuples of any size
+//!   (`arbitrary` feature)
+//! - `StandardTuple` and `TupleDistribution` - random sampling of tuples of
+//!   any size (`rand` feature)
+//! - `TupleZeroize` and `ZeroizingTuple` - zeroizing secrets held in tuples
+//!   (`zeroize` feature)
+//! - `ApproxTuple` - elementwise approximate equality of float tuples
+//!   (`approx` feature)
+//! - `TupleIntoHList` and `HListIntoTuple` - conversions to and from frunk
+//!   `HList`s (`frunk` feature)
+//! - `PythonTuple` - conversions to and from Python tuples of any size (`pyo3`
+//!   feature)
+//! - `TupleIntoJsArray` and `TupleFromJsArray` - conversions to and from
+//!   `js_sys::Array` (`wasm` feature)
+//! - `TupleTypenum` and `TupleGenericArray` - typenum lengths and conversions to
+//!   and from `GenericArray` (`generic_array` feature)
+//! - `TupleSmallVec` - conversions to and from `SmallVec` for homogeneous
+//!   tuples (`smallvec` feature)
+//! - `TupleArrayVec` and `TupleHeaplessVec` - conversions to and from
+//!   `ArrayVec` and `heapless::Vec` for homogeneous tuples, without allocating
+//!   (`arrayvec` and `heapless` features)
+//! - `TupleNdarray` - conversions to `ndarray::Array1` and from
+//!   `ArrayView1` for homogeneous tuples (`ndarray` feature)
+//! - `TupleNalgebra` - conversions to and from `nalgebra` `SVector`s and
+//!   `Point`s for homogeneous tuples of up to 16 elements (`nalgebra` feature)
+//! - `TupleGlam` - conversions between 2, 3 and 4 element tuples and the
+//!   matching `glam` vector types (`glam` feature)
+//! - `TupleRecord` - record tuple elements as indexed span fields (`tracing`
+//!   feature)
+//! - `par_join` - run a tuple of closures on the rayon pool (`rayon` feature)
+//! - `TupleSimd` - conversion to and from `std::simd::Simd` (`portable_simd`
+//!   feature, nightly only)
+//! - [`TupleApplyEach`] - apply a tuple of functions elementwise
+//! - [`TuplePipe`] - compose a tuple of functions into a pipeline
+//! - [`TupleCall`] - call a function with a tuple of arguments
+//! - [`bind_prefix`] - partially apply a function to a tuple of arguments
+//! - [`curry`] - currying and uncurrying of functions
+//! - [`scope_join`] - run a tuple of closures on scoped threads
+//! - [`TupleThreadJoin`] - join a tuple of thread handles
+//! - [`TupleLockAll`] - lock a tuple of `Mutex`es in a consistent order
+//! - [`TupleRwLockAll`] - read or write lock a tuple of `RwLock`s
+//! - [`TupleBorrowAll`] - borrow a tuple of `RefCell`s
 //!
 //! The traits are implemented for tuples from zero len (ie, `()` unit) to 16.
 //! (More with with the `tuple_24` and `tuple_32` features enabled.)
@@ -24,12 +152,134 @@
 //! let out: (_, (_,_,_)) = (1,2,3,4,5).split();
 //! println!("out {out:?}");
 //! ```
+#![cfg_attr(feature = "portable_simd", feature(portable_simd))]
+
 use seq_macro::seq;
+// For exported macros which generate impls for every arity.
+#[doc(hidden)]
+pub use seq_macro::seq as __seq;
+
+// Allow the derive macros to refer to `::tuplestructops` within this crate.
+extern crate self as tuplestructops;
 
 #[cfg(test)]
 mod test;
 
+#[macro_use]
 mod macro_impl;
+#[cfg(feature = "approx")]
+pub mod approx;
+#[cfg(feature = "arbitrary")]
+pub mod arbitrary;
+#[cfg(feature = "rkyv")]
+pub mod archive;
+mod arith;
+#[cfg(feature = "arrayvec")]
+pub mod arrayvec;
+mod call;
+mod cons;
+pub mod convert;
+#[cfg(feature = "csv")]
+pub mod csv;
+pub mod curry;
+pub mod dynamic;
+pub mod fmt;
+#[cfg(feature = "futures")]
+pub mod future;
+#[cfg(feature = "generic_array")]
+pub mod generic_array;
+#[cfg(feature = "glam")]
+pub mod glam;
+#[cfg(feature = "heapless")]
+pub mod heapless;
+#[cfg(feature = "frunk")]
+pub mod hlist;
+pub mod homogeneous;
+mod idx;
+pub mod iter;
+#[cfg(feature = "serde_json")]
+pub mod json;
+mod lock;
+mod map;
+#[cfg(feature = "nalgebra")]
+pub mod nalgebra;
+#[cfg(feature = "ndarray")]
+pub mod ndarray;
+mod par;
+#[cfg(feature = "bytemuck")]
+pub mod pod;
+#[cfg(feature = "pyo3")]
+pub mod python;
+#[cfg(feature = "quickcheck")]
+pub mod quickcheck;
+#[cfg(feature = "rand")]
+pub mod rand;
+mod reflect;
+#[cfg(feature = "schemars")]
+pub mod schema;
+#[cfg(feature = "serde")]
+pub mod serde;
+#[cfg(feature = "portable_simd")]
+pub mod simd;
+#[cfg(feature = "smallvec")]
+pub mod smallvec;
+mod soa;
+#[cfg(feature = "proptest")]
+pub mod strategy;
+mod structs;
+#[cfg(feature = "tokio")]
+pub mod task;
+#[cfg(feature = "tracing")]
+pub mod tracing;
+mod transpose;
+mod tup;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+pub mod wide;
+#[cfg(feature = "zeroize")]
+pub mod zeroize;
+
+pub use arith::{
+    PrimInt, TupleAdd, TupleChecked, TupleDiv, TupleMinMax, TupleMul, TupleSaturating, TupleSub,
+    TupleWrapping,
+};
+pub use call::{bind_prefix, TupleApplyEach, TupleCall, TuplePipe};
+pub use cons::{Cons, ConsIntoTuple, TupleIntoCons};
+pub use convert::{parse_tuple, TupleAnyVec, TupleConvert, TupleFromStr, TupleTryConvert};
+pub use dynamic::{DynTuple, TupleAsDyn, TupleForEachDyn, TupleIdxDyn};
+pub use fmt::{TupleDebug, TupleDisplay};
+#[cfg(feature = "futures")]
+pub use future::{
+    AsyncMapper, TupleJoinAll, TupleMapAsync, TupleRace, TupleTryJoin, TupleZipStreams,
+};
+pub use homogeneous::{from_fn, repeat, Float, HomogeneousTuple, TupleRepeat, TupleSort};
+pub use idx::{TupleGet, TupleIdx, TupleLen};
+pub use iter::{IteratorUnzipN, TupleCartesian, TupleUnzipInto, TupleZipIters};
+pub use lock::{TupleBorrowAll, TupleLockAll, TupleRwLockAll};
+pub use map::{
+    BiFolder, BiMapper, TryMapper, TupleEnumerate, TupleFoldWith, TupleTryMap, TupleZipWith,
+};
+#[cfg(feature = "rayon")]
+pub use par::{par_join, TupleParJoin};
+pub use par::{scope_join, TupleScopeJoin, TupleThreadJoin};
+pub use reflect::{layout_of, TupleLayout, TupleTypeIds, TupleTypeNames};
+#[cfg(feature = "portable_simd")]
+pub use simd::TupleSimd;
+pub use soa::{ArrayOfTuples, TupleOfArrays};
+pub use structs::{TupleLike, TupleOps};
+#[cfg(feature = "tokio")]
+pub use task::{TupleJoinHandles, TupleSpawnAll};
+pub use transpose::{
+    unzip_option, unzip_result, TupleTakeAll, TupleTranspose, TupleTryCollect,
+    TupleUnwrapOrDefault, TupleUnzip, TupleValidate,
+};
+pub use tup::Tup;
+#[cfg(feature = "derive")]
+pub use tuplestructops_derive::{TupleLike, TupleOps};
+pub use wide::{
+    default_tuple, TupleClone, TupleDefault, TupleEq, TupleHash, TupleOrd, TuplePartialEq,
+    TuplePartialOrd,
+};
 
 /// Implement `join` for tuples.
 ///
@@ -0,0 +1,373 @@
+use std::cell::{Ref, RefCell, RefMut};
+use std::sync::{
+    LockResult, Mutex, MutexGuard, PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard,
+    TryLockError, TryLockResult,
+};
+
+use super::*;
+
+/// Lock a tuple of `Mutex`es, or references to `Mutex`es, together.
+///
+/// The locks are always acquired in order of the mutexes' addresses, rather
+/// than their order in the tuple. This means that different call sites can lock
+/// overlapping sets of mutexes, in any tuple order, without deadlocking against
+/// each other (so long as they don't also hold other locks).
+///
+/// # Panics
+///
+/// Locking a tuple of references panics if the same mutex appears twice in
+/// it, since that would otherwise deadlock.
+/// ```rust,should_panic
+/// # use std::sync::Mutex;
+/// # use tuplestructops::TupleLockAll;
+/// let m = Mutex::new(1);
+/// let _ = (&m, &m).lock_all();
+/// ```
+pub trait TupleLockAll: seal::Sealed {
+    /// Tuple of guards.
+    type Guards<'a>
+    where
+        Self: 'a;
+
+    /// Lock all the mutexes, blocking until they're all acquired, and return
+    /// the tuple of guards. If any mutex is poisoned, the error still contains
+    /// all the guards.
+    /// ```rust
+    /// # use std::sync::Mutex;
+    /// # use tuplestructops::TupleLockAll;
+    /// let state = (Mutex::new(1), Mutex::new(String::from("a")));
+    /// {
+    ///     let (mut num, mut s) = state.lock_all().unwrap();
+    ///     *num += 1;
+    ///     s.push('b');
+    /// }
+    /// assert_eq!(*state.0.lock().unwrap(), 2);
+    /// assert_eq!(*state.1.lock().unwrap(), "ab");
+    /// ```
+    fn lock_all(&self) -> LockResult<Self::Guards<'_>>;
+
+    /// Try to lock all the mutexes without blocking. If any of them is already
+    /// locked then none are acquired, and this returns
+    /// [`TryLockError::WouldBlock`].
+    /// ```rust
+    /// # use std::sync::{Mutex, TryLockError};
+    /// # use tuplestructops::TupleLockAll;
+    /// let state = (Mutex::new(1), Mutex::new(2));
+    /// let held = state.1.lock().unwrap();
+    /// assert!(matches!(state.try_lock_all(), Err(TryLockError::WouldBlock)));
+    /// drop(held);
+    /// assert!(state.try_lock_all().is_ok());
+    /// ```
+    fn try_lock_all(&self) -> TryLockResult<Self::Guards<'_>>;
+}
+
+/// Lock a tuple of `RwLock`s, or references to `RwLock`s, together.
+///
+/// As with [`TupleLockAll`], the locks are always acquired in order of their
+/// addresses, to avoid deadlocks between call sites.
+///
+/// # Panics
+///
+/// Locking a tuple of references panics if the same lock appears twice in it.
+/// Taking the write lock twice would deadlock, and taking a read lock
+/// recursively can deadlock if a writer is waiting.
+pub trait TupleRwLockAll: seal::Sealed {
+    /// Tuple of read guards.
+    type ReadGuards<'a>
+    where
+        Self: 'a;
+    /// Tuple of write guards.
+    type WriteGuards<'a>
+    where
+        Self: 'a;
+
+    /// Acquire shared read access to all the locks, blocking until they're
+    /// all acquired, and return the tuple of guards. If any lock is poisoned,
+    /// the error still contains all the guards.
+    /// ```rust
+    /// # use std::sync::RwLock;
+    /// # use tuplestructops::TupleRwLockAll;
+    /// let config = (RwLock::new(8080), RwLock::new("localhost"));
+    /// let (port, host) = config.read_all().unwrap();
+    /// // Shared access allows concurrent readers
+    /// let (port2, host2) = config.read_all().unwrap();
+    /// assert_eq!((*port, *host), (*port2, *host2));
+    /// ```
+    fn read_all(&self) -> LockResult<Self::ReadGuards<'_>>;
+
+    /// Acquire exclusive write access to all the locks, blocking until
+    /// they're all acquired, and return the tuple of guards. If any lock is
+    /// poisoned, the error still contains all the guards.
+    /// ```rust
+    /// # use std::sync::RwLock;
+    /// # use tuplestructops::TupleRwLockAll;
+    /// let config = (RwLock::new(8080), RwLock::new("localhost"));
+    /// {
+    ///     let (mut port, mut host) = config.write_all().unwrap();
+    ///     (*port, *host) = (443, "example.com");
+    /// }
+    /// assert_eq!(*config.0.read().unwrap(), 443);
+    /// ```
+    fn write_all(&self) -> LockResult<Self::WriteGuards<'_>>;
+}
+
+/// Borrow a tuple of `RefCell`s, or references to `RefCell`s, together.
+pub trait TupleBorrowAll: seal::Sealed {
+    /// Tuple of shared borrows.
+    type Refs<'a>
+    where
+        Self: 'a;
+    /// Tuple of mutable borrows.
+    type RefMuts<'a>
+    where
+        Self: 'a;
+
+    /// [`RefCell::borrow`] each element.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the cells is currently mutably borrowed.
+    /// ```rust
+    /// # use std::cell::RefCell;
+    /// # use tuplestructops::TupleBorrowAll;
+    /// let cells = (RefCell::new(1), RefCell::new("a"));
+    /// let (a, b) = cells.borrow_all();
+    /// assert_eq!((*a, *b), (1, "a"));
+    /// ```
+    fn borrow_all(&self) -> Self::Refs<'_>;
+
+    /// [`RefCell::borrow_mut`] each element.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the cells is currently borrowed.
+    /// ```rust
+    /// # use std::cell::RefCell;
+    /// # use tuplestructops::TupleBorrowAll;
+    /// let cells = (RefCell::new(1), RefCell::new("a"));
+    /// {
+    ///     let (mut a, mut b) = cells.borrow_mut_all();
+    ///     (*a, *b) = (2, "b");
+    /// }
+    /// assert_eq!(cells, (RefCell::new(2), RefCell::new("b")));
+    /// ```
+    fn borrow_mut_all(&self) -> Self::RefMuts<'_>;
+}
+
+// Sort `(address, index)` pairs into locking order, panicking if any address
+// appears twice, as locking the same lock twice would deadlock.
+fn lock_order<const N: usize>(mut order: [(usize, usize); N], what: &str) -> [(usize, usize); N] {
+    order.sort_unstable();
+    if order.windows(2).any(|pair| pair[0].0 == pair[1].0) {
+        panic!("same {what} appears twice in tuple");
+    }
+    order
+}
+
+// Acquire all the locks of a tuple of references with `$lock`, in address
+// order to get a consistent locking order, returning a `LockResult` of the
+// tuple of guards.
+macro_rules! lock_ordered {
+    ($n:literal, $this:ident, $lock:ident, $what:literal) => {
+        seq!(J in 0..$n {{
+            let order = lock_order([#(($this.J as *const _ as usize, J),)*], $what);
+
+            #(let mut guard~J = None;)*
+            let mut poisoned = false;
+
+            for (_, idx) in order {
+                match idx {
+                    #(J => guard~J = Some($this.J.$lock().unwrap_or_else(|err| {
+                        poisoned = true;
+                        err.into_inner()
+                    })),)*
+                    _ => unreachable!(),
+                }
+            }
+
+            let guards = (#(guard~J.unwrap(),)*);
+            if poisoned {
+                Err(PoisonError::new(guards))
+            } else {
+                Ok(guards)
+            }
+        }})
+    };
+}
+
+macro_rules! impl_lock {
+    (0) => {
+        #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+        impl TupleLockAll for () {
+            type Guards<'a> = ();
+
+            fn lock_all(&self) -> LockResult<()> {
+                Ok(())
+            }
+
+            fn try_lock_all(&self) -> TryLockResult<()> {
+                Ok(())
+            }
+        }
+    };
+    ($n:literal) => {
+        seq!(J in 0..$n {
+            // Lock by value
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<#(T~J,)*> TupleLockAll for (#(Mutex<T~J>,)*) {
+                type Guards<'a> = (#(MutexGuard<'a, T~J>,)*) where Self: 'a;
+
+                fn lock_all(&self) -> LockResult<Self::Guards<'_>> {
+                    (#(&self.J,)*).lock_all()
+                }
+
+                fn try_lock_all(&self) -> TryLockResult<Self::Guards<'_>> {
+                    (#(&self.J,)*).try_lock_all()
+                }
+            }
+
+            // Lock by reference
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<'m, #(T~J,)*> TupleLockAll for (#(&'m Mutex<T~J>,)*) {
+                type Guards<'a> = (#(MutexGuard<'m, T~J>,)*) where Self: 'a;
+
+                fn lock_all(&self) -> LockResult<Self::Guards<'_>> {
+                    lock_ordered!($n, self, lock, "mutex")
+                }
+
+                fn try_lock_all(&self) -> TryLockResult<Self::Guards<'_>> {
+                    let order = lock_order([#((self.J as *const _ as usize, J),)*], "mutex");
+
+                    #(let mut guard~J = None;)*
+                    let mut poisoned = false;
+
+                    // Any guards already acquired are dropped on `WouldBlock`
+                    for (_, idx) in order {
+                        match idx {
+                            #(J => guard~J = Some(match self.J.try_lock() {
+                                Ok(guard) => guard,
+                                Err(TryLockError::Poisoned(err)) => {
+                                    poisoned = true;
+                                    err.into_inner()
+                                }
+                                Err(TryLockError::WouldBlock) => return Err(TryLockError::WouldBlock),
+                            }),)*
+                            _ => unreachable!(),
+                        }
+                    }
+
+                    let guards = (#(guard~J.unwrap(),)*);
+                    if poisoned {
+                        Err(TryLockError::Poisoned(PoisonError::new(guards)))
+                    } else {
+                        Ok(guards)
+                    }
+                }
+            }
+        });
+    };
+}
+
+for_each_tuple!(impl_lock);
+
+macro_rules! impl_rwlock {
+    (0) => {
+        #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+        impl TupleRwLockAll for () {
+            type ReadGuards<'a> = ();
+            type WriteGuards<'a> = ();
+
+            fn read_all(&self) -> LockResult<()> {
+                Ok(())
+            }
+
+            fn write_all(&self) -> LockResult<()> {
+                Ok(())
+            }
+        }
+    };
+    ($n:literal) => {
+        seq!(J in 0..$n {
+            // Lock by value
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<#(T~J,)*> TupleRwLockAll for (#(RwLock<T~J>,)*) {
+                type ReadGuards<'a> = (#(RwLockReadGuard<'a, T~J>,)*) where Self: 'a;
+                type WriteGuards<'a> = (#(RwLockWriteGuard<'a, T~J>,)*) where Self: 'a;
+
+                fn read_all(&self) -> LockResult<Self::ReadGuards<'_>> {
+                    (#(&self.J,)*).read_all()
+                }
+
+                fn write_all(&self) -> LockResult<Self::WriteGuards<'_>> {
+                    (#(&self.J,)*).write_all()
+                }
+            }
+
+            // Lock by reference
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<'m, #(T~J,)*> TupleRwLockAll for (#(&'m RwLock<T~J>,)*) {
+                type ReadGuards<'a> = (#(RwLockReadGuard<'m, T~J>,)*) where Self: 'a;
+                type WriteGuards<'a> = (#(RwLockWriteGuard<'m, T~J>,)*) where Self: 'a;
+
+                fn read_all(&self) -> LockResult<Self::ReadGuards<'_>> {
+                    lock_ordered!($n, self, read, "rwlock")
+                }
+
+                fn write_all(&self) -> LockResult<Self::WriteGuards<'_>> {
+                    lock_ordered!($n, self, write, "rwlock")
+                }
+            }
+        });
+    };
+}
+
+for_each_tuple!(impl_rwlock);
+
+macro_rules! impl_borrow {
+    (0) => {
+        #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+        impl TupleBorrowAll for () {
+            type Refs<'a> = ();
+            type RefMuts<'a> = ();
+
+            fn borrow_all(&self) {}
+
+            fn borrow_mut_all(&self) {}
+        }
+    };
+    ($n:literal) => {
+        seq!(J in 0..$n {
+            // Borrow by value
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<#(T~J,)*> TupleBorrowAll for (#(RefCell<T~J>,)*) {
+                type Refs<'a> = (#(Ref<'a, T~J>,)*) where Self: 'a;
+                type RefMuts<'a> = (#(RefMut<'a, T~J>,)*) where Self: 'a;
+
+                fn borrow_all(&self) -> Self::Refs<'_> {
+                    (#(self.J.borrow(),)*)
+                }
+
+                fn borrow_mut_all(&self) -> Self::RefMuts<'_> {
+                    (#(self.J.borrow_mut(),)*)
+                }
+            }
+
+            // Borrow by reference
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<'c, #(T~J,)*> TupleBorrowAll for (#(&'c RefCell<T~J>,)*) {
+                type Refs<'a> = (#(Ref<'c, T~J>,)*) where Self: 'a;
+                type RefMuts<'a> = (#(RefMut<'c, T~J>,)*) where Self: 'a;
+
+                fn borrow_all(&self) -> Self::Refs<'_> {
+                    (#(self.J.borrow(),)*)
+                }
+
+                fn borrow_mut_all(&self) -> Self::RefMuts<'_> {
+                    (#(self.J.borrow_mut(),)*)
+                }
+            }
+        });
+    };
+}
+
+for_each_tuple!(impl_borrow);
@@ -73,6 +73,7 @@ macro_rules! tuple_impl {
                 seq!(J in 0..N {
                     impl<#(T~J,)*> seal::Sealed for (#(T~J,)*) {}
                     impl<'a, #(T~J,)*> seal::Sealed for &'a (#(T~J,)*) {}
+                    impl<'a, #(T~J,)*> seal::Sealed for &'a mut (#(T~J,)*) {}
 
                     impl_joinsplit!(#(T~J)*);
                 });
@@ -86,3 +87,173 @@ tuple_impl!(0, 16);
 tuple_impl!(17, 24);
 #[cfg(any(feature = "tuple_32"))]
 tuple_impl!(25, 32);
+
+/// Invoke `$mac!(N)` once for every supported tuple arity `N`. The callee
+/// generally uses `seq!(J in 0..N { ... })` to generate per-element names.
+macro_rules! for_each_tuple {
+    ($mac:ident) => {
+        for_each_tuple!(@range $mac, 0, 16);
+        #[cfg(any(feature = "tuple_32", feature = "tuple_24"))]
+        for_each_tuple!(@range $mac, 17, 24);
+        #[cfg(feature = "tuple_32")]
+        for_each_tuple!(@range $mac, 25, 32);
+    };
+    (@range $mac:ident, $low:literal, $high:literal) => {
+        seq!(N in $low..=$high {
+            $mac!(N);
+        });
+    };
+}
+
+// The highest arity depends on this crate's features, so exported macros get
+// it from here rather than checking features in the caller's crate. This
+// invokes `$crate::$mac!(@arities MAX, ...)`.
+#[cfg(not(any(feature = "tuple_24", feature = "tuple_32")))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __with_max_arity {
+    ($mac:ident, $($args:tt)*) => {
+        $crate::$mac!(@arities 16, $($args)*);
+    };
+}
+
+#[cfg(all(feature = "tuple_24", not(feature = "tuple_32")))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __with_max_arity {
+    ($mac:ident, $($args:tt)*) => {
+        $crate::$mac!(@arities 24, $($args)*);
+    };
+}
+
+#[cfg(feature = "tuple_32")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __with_max_arity {
+    ($mac:ident, $($args:tt)*) => {
+        $crate::$mac!(@arities 32, $($args)*);
+    };
+}
+
+/// Implement a trait for all tuples whose elements implement it, by calling
+/// each method on every element and combining the results.
+///
+/// Each method is declared with its signature followed by `=>` and the
+/// strategy for combining the per-element results, which are computed from
+/// left to right:
+/// - `each` - ignore the results
+/// - `all` / `any` - logical and / or of `bool` results, short-circuiting
+/// - `sum` / `product` - add or multiply the results, with [`Sum`] or
+///   [`Product`]
+/// - `fold(init, f)` - fold the results with `f(acc, result)`, starting with
+///   `init`
+///
+/// Methods must take `&self` or `&mut self`, and any other arguments are
+/// passed to every element, so must be `Copy`. Traits with generic methods,
+/// associated types or constants aren't supported. This must be invoked once,
+/// in the crate defining the trait.
+/// ```rust
+/// # use tuplestructops::impl_for_tuples;
+/// trait Weight {
+///     fn weight(&self) -> u32;
+///     fn heaviest(&self) -> u32;
+///     fn is_light(&self, limit: u32) -> bool;
+///     fn double(&mut self);
+/// }
+///
+/// impl Weight for u32 {
+///     fn weight(&self) -> u32 { *self }
+///     fn heaviest(&self) -> u32 { *self }
+///     fn is_light(&self, limit: u32) -> bool { *self < limit }
+///     fn double(&mut self) { *self *= 2 }
+/// }
+///
+/// impl_for_tuples!(Weight {
+///     fn weight(&self) -> u32 => sum;
+///     fn heaviest(&self) -> u32 => fold(0, u32::max);
+///     fn is_light(&self, limit: u32) -> bool => all;
+///     fn double(&mut self) => each;
+/// });
+///
+/// let mut t = (1u32, (2u32, 3u32), ());
+/// assert_eq!(t.weight(), 6);
+/// assert!(t.is_light(4));
+/// t.double();
+/// assert_eq!(t.heaviest(), 6);
+/// ```
+/// [`Sum`]: std::iter::Sum
+/// [`Product`]: std::iter::Product
+#[macro_export]
+macro_rules! impl_for_tuples {
+    ($tr:path { $($methods:tt)* }) => {
+        $crate::__with_max_arity!(impl_for_tuples, $tr { $($methods)* });
+    };
+    (@arities $max:literal, $tr:path { $($methods:tt)* }) => {
+        $crate::__seq!(__N in 0..=$max {
+            #( $crate::__seq!(__J in 0..__N {
+                impl<#(__T~__J: $tr,)*> $tr for (#(__T~__J,)*) {
+                    $crate::impl_for_tuples!(@methods (#(__J)*) $($methods)*);
+                }
+            }); )*
+        });
+    };
+
+    // Generate each method, passing its arguments as a single group so it
+    // can be repeated for each element.
+    (@methods $idxs:tt) => {};
+    (@methods $idxs:tt
+        fn $name:ident(&self $(, $arg:ident: $argty:ty)* $(,)?) $(-> $ret:ty)?
+            => $strategy:ident $(($($sarg:tt)*))?;
+        $($rest:tt)*
+    ) => {
+        #[allow(unused_variables, clippy::let_and_return, clippy::unused_unit)]
+        fn $name(&self $(, $arg: $argty)*) $(-> $ret)? {
+            $crate::impl_for_tuples!(
+                @body $strategy ($($($sarg)*)?) [$($ret)?] self $name ($($arg),*) $idxs
+            )
+        }
+        $crate::impl_for_tuples!(@methods $idxs $($rest)*);
+    };
+    (@methods $idxs:tt
+        fn $name:ident(&mut self $(, $arg:ident: $argty:ty)* $(,)?) $(-> $ret:ty)?
+            => $strategy:ident $(($($sarg:tt)*))?;
+        $($rest:tt)*
+    ) => {
+        #[allow(unused_variables, clippy::let_and_return, clippy::unused_unit)]
+        fn $name(&mut self $(, $arg: $argty)*) $(-> $ret)? {
+            $crate::impl_for_tuples!(
+                @body $strategy ($($($sarg)*)?) [$($ret)?] self $name ($($arg),*) $idxs
+            )
+        }
+        $crate::impl_for_tuples!(@methods $idxs $($rest)*);
+    };
+
+    (@body $strategy:ident $sargs:tt $ret:tt $self:ident $name:ident $args:tt ($($idx:tt)*)) => {
+        $crate::impl_for_tuples!(@combine $strategy $sargs $ret $($self.$idx.$name $args,)*)
+    };
+
+    (@combine each () $ret:tt $($call:expr,)*) => {{
+        $($call;)*
+    }};
+    (@combine all () $ret:tt $($call:expr,)*) => {
+        $($call &&)* true
+    };
+    (@combine any () $ret:tt $($call:expr,)*) => {
+        $($call ||)* false
+    };
+    (@combine sum () [$ret:ty] $($call:expr,)*) => {
+        ::core::iter::empty::<$ret>()
+            $(.chain(::core::iter::once($call)))*
+            .sum::<$ret>()
+    };
+    (@combine product () [$ret:ty] $($call:expr,)*) => {
+        ::core::iter::empty::<$ret>()
+            $(.chain(::core::iter::once($call)))*
+            .product::<$ret>()
+    };
+    (@combine fold ($init:expr, $f:expr) $ret:tt $($call:expr,)*) => {{
+        let acc = $init;
+        $(let acc = ($f)(acc, $call);)*
+        acc
+    }};
+}
@@ -0,0 +1,341 @@
+use super::*;
+
+/// Fallible mapping of a single element, used by [`TupleTryMap`].
+///
+/// A `TryMapper` must be implemented for every element type of the tuple being
+/// mapped, typically via a generic impl. All the implementations must share the
+/// same `Error` type.
+pub trait TryMapper<T> {
+    /// Result of mapping an element of type `T`.
+    type Output;
+    /// Error returned when an element fails to map.
+    type Error;
+
+    /// Map a single element.
+    fn try_map(&mut self, elem: T) -> Result<Self::Output, Self::Error>;
+}
+
+/// Fallibly map each element of a tuple with a [`TryMapper`].
+pub trait TupleTryMap<M>: seal::Sealed {
+    /// Tuple of mapped elements.
+    type Output;
+    /// Error from the mapper.
+    type Error;
+
+    /// Map each element of the tuple from left to right, returning the first
+    /// error encountered. Elements after the failing one are not mapped.
+    /// ```rust
+    /// # use tuplestructops::{TryMapper, TupleTryMap};
+    /// use std::fmt::Display;
+    ///
+    /// struct NonEmpty;
+    ///
+    /// impl<T: Display> TryMapper<T> for NonEmpty {
+    ///     type Output = String;
+    ///     type Error = &'static str;
+    ///
+    ///     fn try_map(&mut self, elem: T) -> Result<String, &'static str> {
+    ///         let s = elem.to_string();
+    ///         if s.is_empty() { Err("empty") } else { Ok(s) }
+    ///     }
+    /// }
+    ///
+    /// assert_eq!((1, 'a', "b").try_map(NonEmpty), Ok(("1".to_string(), "a".to_string(), "b".to_string())));
+    /// assert_eq!((1, "", 'c').try_map(NonEmpty), Err("empty"));
+    /// ```
+    fn try_map(self, mapper: M) -> Result<Self::Output, Self::Error>;
+}
+
+macro_rules! impl_try_map {
+    (0) => {
+        #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+        impl<M> TupleTryMap<M> for () {
+            type Output = ();
+            type Error = std::convert::Infallible;
+
+            fn try_map(self, _mapper: M) -> Result<(), Self::Error> {
+                Ok(())
+            }
+        }
+
+        #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+        impl<'a, M> TupleTryMap<M> for &'a () {
+            type Output = ();
+            type Error = std::convert::Infallible;
+
+            fn try_map(self, _mapper: M) -> Result<(), Self::Error> {
+                Ok(())
+            }
+        }
+    };
+    ($n:literal) => {
+        seq!(J in 0..$n {
+            // Map by value
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<M, E, #(T~J,)*> TupleTryMap<M> for (#(T~J,)*)
+            where
+                #(M: TryMapper<T~J, Error = E>,)*
+            {
+                type Output = (#(<M as TryMapper<T~J>>::Output,)*);
+                type Error = E;
+
+                #[allow(non_snake_case)]
+                fn try_map(self, mut mapper: M) -> Result<Self::Output, E> {
+                    let (#(T~J,)*) = self;
+
+                    Ok((#(mapper.try_map(T~J)?,)*))
+                }
+            }
+
+            // Map by reference
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<'a, M, E, #(T~J,)*> TupleTryMap<M> for &'a (#(T~J,)*)
+            where
+                #(M: TryMapper<&'a T~J, Error = E>,)*
+            {
+                type Output = (#(<M as TryMapper<&'a T~J>>::Output,)*);
+                type Error = E;
+
+                #[allow(non_snake_case)]
+                fn try_map(self, mut mapper: M) -> Result<Self::Output, E> {
+                    let (#(T~J,)*) = self;
+
+                    Ok((#(mapper.try_map(T~J)?,)*))
+                }
+            }
+        });
+    };
+}
+
+for_each_tuple!(impl_try_map);
+
+/// Pair each element of a tuple with its index.
+pub trait TupleEnumerate: seal::Sealed {
+    /// Tuple of `(usize, T)` pairs.
+    type Output;
+
+    /// Convert `(A, B, ...)` into `((0, A), (1, B), ...)`. This makes the
+    /// position of each element available to a mapper.
+    /// ```rust
+    /// # use tuplestructops::TupleEnumerate;
+    /// assert_eq!(('a', "b").enumerate(), ((0, 'a'), (1, "b")));
+    /// assert_eq!((&('a', "b")).enumerate(), ((0, &'a'), (1, &"b")));
+    /// ```
+    fn enumerate(self) -> Self::Output;
+}
+
+macro_rules! impl_enumerate {
+    ($n:literal) => {
+        seq!(J in 0..$n {
+            // Enumerate by value
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<#(T~J,)*> TupleEnumerate for (#(T~J,)*) {
+                type Output = (#((usize, T~J),)*);
+
+                #[allow(clippy::unused_unit, non_snake_case)]
+                fn enumerate(self) -> Self::Output {
+                    let (#(T~J,)*) = self;
+
+                    (#((J, T~J),)*)
+                }
+            }
+
+            // Enumerate by reference
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<'a, #(T~J,)*> TupleEnumerate for &'a (#(T~J,)*) {
+                type Output = (#((usize, &'a T~J),)*);
+
+                #[allow(clippy::unused_unit, non_snake_case)]
+                fn enumerate(self) -> Self::Output {
+                    let (#(T~J,)*) = self;
+
+                    (#((J, T~J),)*)
+                }
+            }
+        });
+    };
+}
+
+for_each_tuple!(impl_enumerate);
+
+/// Combining of a pair of elements, used by [`TupleZipWith`].
+///
+/// A `BiMapper` must be implemented for every pair of element types at the
+/// same position in the tuples being zipped, typically via a generic impl.
+///
+/// It is also implemented for closures, which can be used to zip homogeneous
+/// tuples (or more generally, tuples where the pairs of elements at each
+/// position have the same types).
+pub trait BiMapper<L, R> {
+    /// Result of combining an element of type `L` with one of type `R`.
+    type Output;
+
+    /// Combine a pair of elements.
+    fn combine(&mut self, left: L, right: R) -> Self::Output;
+}
+
+impl<F, L, R, O> BiMapper<L, R> for F
+where
+    F: FnMut(L, R) -> O,
+{
+    type Output = O;
+
+    fn combine(&mut self, left: L, right: R) -> O {
+        self(left, right)
+    }
+}
+
+/// Combine two tuples of the same length elementwise with a [`BiMapper`].
+pub trait TupleZipWith<RHS, M>: seal::Sealed {
+    /// Tuple of combined elements.
+    type Output;
+
+    /// Combine each element of `self` with the corresponding element of
+    /// `other`, from left to right.
+    /// ```rust
+    /// # use tuplestructops::{BiMapper, TupleZipWith};
+    /// struct Override;
+    ///
+    /// impl<T> BiMapper<T, Option<T>> for Override {
+    ///     type Output = T;
+    ///
+    ///     fn combine(&mut self, default: T, value: Option<T>) -> T {
+    ///         value.unwrap_or(default)
+    ///     }
+    /// }
+    ///
+    /// let defaults = (8080, "localhost", false);
+    /// let config = (None, Some("example.com"), Some(true));
+    /// assert_eq!(defaults.zip_with(config, Override), (8080, "example.com", true));
+    /// ```
+    /// With a closure, the element types at each position must match.
+    /// ```rust
+    /// # use tuplestructops::TupleZipWith;
+    /// let brightest = (200u8, 10, 30).zip_with((100u8, 80, 60), u8::max);
+    /// assert_eq!(brightest, (200, 80, 60));
+    ///
+    /// let mixed = (0.5, 1.0).zip_with((1.5, 3.0), |a: f32, b: f32| (a + b) / 2.0);
+    /// assert_eq!(mixed, (1.0, 2.0));
+    /// ```
+    fn zip_with(self, other: RHS, mapper: M) -> Self::Output;
+}
+
+macro_rules! impl_zip_with {
+    ($n:literal) => {
+        seq!(J in 0..$n {
+            // Zip by value
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<M, #(L~J, R~J,)*> TupleZipWith<(#(R~J,)*), M> for (#(L~J,)*)
+            where
+                #(M: BiMapper<L~J, R~J>,)*
+            {
+                type Output = (#(<M as BiMapper<L~J, R~J>>::Output,)*);
+
+                #[allow(clippy::unused_unit, non_snake_case, unused_mut, unused_variables)]
+                fn zip_with(self, other: (#(R~J,)*), mut mapper: M) -> Self::Output {
+                    let (#(L~J,)*) = self;
+                    let (#(R~J,)*) = other;
+
+                    (#(mapper.combine(L~J, R~J),)*)
+                }
+            }
+
+            // Zip by reference
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<'a, M, #(L~J, R~J,)*> TupleZipWith<&'a (#(R~J,)*), M> for &'a (#(L~J,)*)
+            where
+                #(M: BiMapper<&'a L~J, &'a R~J>,)*
+            {
+                type Output = (#(<M as BiMapper<&'a L~J, &'a R~J>>::Output,)*);
+
+                #[allow(clippy::unused_unit, non_snake_case, unused_mut, unused_variables)]
+                fn zip_with(self, other: &'a (#(R~J,)*), mut mapper: M) -> Self::Output {
+                    let (#(L~J,)*) = self;
+                    let (#(R~J,)*) = other;
+
+                    (#(mapper.combine(L~J, R~J),)*)
+                }
+            }
+        });
+    };
+}
+
+for_each_tuple!(impl_zip_with);
+
+/// Folding of a pair of elements into an accumulator, used by
+/// [`TupleFoldWith`].
+///
+/// A `BiFolder` must be implemented for every pair of element types at the
+/// same position in the tuples being folded, typically via a generic impl.
+pub trait BiFolder<A, L, R> {
+    /// Fold a pair of elements into the accumulator.
+    fn fold(&mut self, acc: A, left: L, right: R) -> A;
+}
+
+/// Fold over two tuples of the same length in lockstep with a [`BiFolder`].
+pub trait TupleFoldWith<RHS, A, F>: seal::Sealed {
+    /// Fold each element of `self` and the corresponding element of `other`
+    /// into an accumulator, starting with `init`, from left to right.
+    /// ```rust
+    /// # use tuplestructops::{BiFolder, TupleFoldWith};
+    /// use std::cmp::Ordering;
+    ///
+    /// // Lexicographic comparison of tuples with different element types
+    /// struct Compare;
+    ///
+    /// impl<L: PartialOrd<R>, R> BiFolder<Ordering, L, R> for Compare {
+    ///     fn fold(&mut self, acc: Ordering, left: L, right: R) -> Ordering {
+    ///         acc.then_with(|| left.partial_cmp(&right).unwrap())
+    ///     }
+    /// }
+    ///
+    /// let left = (1, "b", 3.0);
+    /// let right = (1, "a", 4.0);
+    /// assert_eq!(left.fold_with(right, Ordering::Equal, Compare), Ordering::Greater);
+    /// ```
+    fn fold_with(self, other: RHS, init: A, folder: F) -> A;
+}
+
+macro_rules! impl_fold_with {
+    ($n:literal) => {
+        seq!(J in 0..$n {
+            // Fold by value
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<A, F, #(L~J, R~J,)*> TupleFoldWith<(#(R~J,)*), A, F> for (#(L~J,)*)
+            where
+                #(F: BiFolder<A, L~J, R~J>,)*
+            {
+                #[allow(non_snake_case, unused_mut, unused_variables)]
+                fn fold_with(self, other: (#(R~J,)*), init: A, mut folder: F) -> A {
+                    let (#(L~J,)*) = self;
+                    let (#(R~J,)*) = other;
+                    let acc = init;
+
+                    #(let acc = folder.fold(acc, L~J, R~J);)*
+
+                    acc
+                }
+            }
+
+            // Fold by reference
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<'a, A, F, #(L~J, R~J,)*> TupleFoldWith<&'a (#(R~J,)*), A, F> for &'a (#(L~J,)*)
+            where
+                #(F: BiFolder<A, &'a L~J, &'a R~J>,)*
+            {
+                #[allow(non_snake_case, unused_mut, unused_variables)]
+                fn fold_with(self, other: &'a (#(R~J,)*), init: A, mut folder: F) -> A {
+                    let (#(L~J,)*) = self;
+                    let (#(R~J,)*) = other;
+                    let acc = init;
+
+                    #(let acc = folder.fold(acc, L~J, R~J);)*
+
+                    acc
+                }
+            }
+        });
+    };
+}
+
+for_each_tuple!(impl_fold_with);
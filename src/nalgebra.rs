@@ -0,0 +1,60 @@
+//! Conversions between homogeneous tuples and [`nalgebra`] vectors and points.
+//!
+//! Tuples of 1 to 16 elements convert to and from [`SVector`] and [`Point`]
+//! of the same dimension, so tuple coordinates can be used for linear algebra:
+//! ```rust
+//! # use nalgebra::{Point3, Vector3};
+//! # use tuplestructops::nalgebra::TupleNalgebra;
+//! let v: Vector3<f64> = (1.0, 2.0, 2.0).into_svector();
+//! assert_eq!(v.norm(), 3.0);
+//!
+//! let p = (1.0, 1.0, 1.0).into_point() + v;
+//! assert_eq!(p, Point3::new(2.0, 3.0, 3.0));
+//! assert_eq!(<(f64, f64, f64)>::from_point(p), (2.0, 3.0, 3.0));
+//! ```
+use ::nalgebra::{Point, SVector, Scalar};
+
+use super::*;
+
+/// Conversion between homogeneous tuples of length `N` and `N`-dimensional
+/// vectors and points.
+pub trait TupleNalgebra<T: Scalar, const N: usize>: HomogeneousTuple<T> {
+    /// Move the elements into a column vector.
+    fn into_svector(self) -> SVector<T, N>;
+
+    /// Construct a tuple from the components of a column vector.
+    fn from_svector(vector: SVector<T, N>) -> Self;
+
+    /// Move the elements into a point.
+    fn into_point(self) -> Point<T, N>;
+
+    /// Construct a tuple from the coordinates of a point.
+    fn from_point(point: Point<T, N>) -> Self;
+}
+
+macro_rules! impl_nalgebra {
+    ($n:literal) => {
+        seq!(J in 0..$n {
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<T: Scalar> TupleNalgebra<T, $n> for (#(T,)*) {
+                fn into_svector(self) -> SVector<T, $n> {
+                    SVector::from(self.into_array())
+                }
+
+                fn from_svector(vector: SVector<T, $n>) -> Self {
+                    Self::from_array(vector.into())
+                }
+
+                fn into_point(self) -> Point<T, $n> {
+                    Point::from(self.into_array())
+                }
+
+                fn from_point(point: Point<T, $n>) -> Self {
+                    Self::from_array(point.into())
+                }
+            }
+        });
+    };
+}
+
+for_each_tuple!(@range impl_nalgebra, 1, 16);
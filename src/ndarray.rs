@@ -0,0 +1,58 @@
+//! Conversions between homogeneous tuples and one-dimensional [`ndarray`]
+//! arrays.
+//!
+//! A tuple converts into an owned [`Array1`], and can be built back from an
+//! [`ArrayView1`] of the same length, including strided views such as a
+//! column of a matrix:
+//! ```rust
+//! # use ndarray::{array, Array1};
+//! # use tuplestructops::ndarray::TupleNdarray;
+//! let a: Array1<f64> = (1.0, 2.0, 3.0).into_array1();
+//! assert_eq!(a.dot(&a), 14.0);
+//!
+//! let m = array![[1, 2], [3, 4], [5, 6]];
+//! assert_eq!(<(i32, i32, i32)>::from_array_view(m.column(1)), Ok((2, 4, 6)));
+//! ```
+use ::ndarray::{Array1, ArrayView1};
+
+use super::homogeneous::LengthError;
+use super::*;
+
+/// Conversion between homogeneous tuples and one-dimensional arrays.
+pub trait TupleNdarray<T>: HomogeneousTuple<T> {
+    /// Move the elements into an `Array1` of the same length.
+    fn into_array1(self) -> Array1<T>;
+
+    /// Construct a tuple by cloning the elements of a one-dimensional view,
+    /// failing if it's not the same length as the tuple.
+    fn from_array_view(view: ArrayView1<'_, T>) -> Result<Self, LengthError>
+    where
+        T: Clone;
+}
+
+macro_rules! impl_ndarray {
+    ($n:literal) => {
+        seq!(J in 0..$n {
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<T> TupleNdarray<T> for (#(T,)*) {
+                fn into_array1(self) -> Array1<T> {
+                    Array1::from_iter(self.into_array())
+                }
+
+                #[allow(unused_mut, unused_variables, clippy::unused_unit)]
+                fn from_array_view(view: ArrayView1<'_, T>) -> Result<Self, LengthError>
+                where
+                    T: Clone,
+                {
+                    if view.len() != $n {
+                        return Err(LengthError::new($n, view.len()));
+                    }
+                    let mut elems = view.iter().cloned();
+                    Ok((#(elems.next().unwrap(),)*))
+                }
+            }
+        });
+    };
+}
+
+for_each_tuple!(impl_ndarray);
@@ -0,0 +1,186 @@
+//! Parallel execution of tuples of closures.
+//!
+//! [`par_join`] uses rayon, and is enabled with the `rayon` feature.
+use std::thread::{self, JoinHandle, ScopedJoinHandle};
+
+use super::*;
+
+/// Tuple of closures which can be run in parallel on the rayon thread pool.
+/// See [`par_join`].
+#[cfg(feature = "rayon")]
+pub trait TupleParJoin: seal::Sealed {
+    /// Tuple of results.
+    type Output;
+
+    /// Run each closure on the rayon pool, and return the tuple of results.
+    fn par_join(self) -> Self::Output;
+}
+
+/// Join a tuple of thread handles.
+pub trait TupleThreadJoin: seal::Sealed {
+    /// Tuple of [`thread::Result`]s.
+    type Output;
+
+    /// Wait for each thread to finish, in order, and return the tuple of their
+    /// results. A thread which panicked has an `Err` result with the panic
+    /// payload. This is implemented for tuples of both [`JoinHandle`]s and
+    /// [`ScopedJoinHandle`]s.
+    /// ```rust
+    /// # use tuplestructops::TupleThreadJoin;
+    /// use std::thread;
+    ///
+    /// let handles = (thread::spawn(|| 1), thread::spawn(|| "two"));
+    /// let (one, two) = handles.join_all();
+    /// assert_eq!((one.unwrap(), two.unwrap()), (1, "two"));
+    ///
+    /// let handles = (thread::spawn(|| 1), thread::spawn(|| panic!("failed")));
+    /// assert!(handles.join_all().1.is_err());
+    /// ```
+    fn join_all(self) -> Self::Output;
+}
+
+/// Tuple of closures which can be run on scoped threads. See [`scope_join`].
+pub trait TupleScopeJoin: seal::Sealed {
+    /// Tuple of results.
+    type Output;
+
+    /// Run each closure on its own scoped thread, and return the tuple of
+    /// results.
+    fn scope_join(self) -> Self::Output;
+}
+
+/// Run each closure in a tuple on its own thread with [`std::thread::scope`],
+/// and return the tuple of their results. The closures may borrow from the
+/// caller. If any closure panics, the panic is propagated once they have all
+/// finished.
+/// ```rust
+/// # use tuplestructops::scope_join;
+/// let data = vec![1, 2, 3, 4];
+/// let (sum, max) = scope_join((
+///     || data.iter().sum::<i32>(),
+///     || data.iter().max().copied(),
+/// ));
+/// assert_eq!((sum, max), (10, Some(4)));
+/// ```
+pub fn scope_join<F: TupleScopeJoin>(fs: F) -> F::Output {
+    fs.scope_join()
+}
+
+/// Run each closure in a tuple on the rayon thread pool, potentially in
+/// parallel, and return the tuple of their results. This generalizes
+/// [`rayon::join`] to any number of closures. If any closure panics, the panic
+/// is propagated once they have all finished.
+/// ```rust
+/// # use tuplestructops::par_join;
+/// let data = vec![1, 2, 3, 4];
+/// let (sum, max, len) = par_join((
+///     || data.iter().sum::<i32>(),
+///     || data.iter().max().copied(),
+///     || data.len(),
+/// ));
+/// assert_eq!((sum, max, len), (10, Some(4), 4));
+/// ```
+#[cfg(feature = "rayon")]
+pub fn par_join<F: TupleParJoin>(fs: F) -> F::Output {
+    fs.par_join()
+}
+
+#[cfg(feature = "rayon")]
+macro_rules! impl_par_join {
+    ($n:literal) => {
+        seq!(J in 0..$n {
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<#(F~J, R~J,)*> TupleParJoin for (#(F~J,)*)
+            where
+                #(F~J: FnOnce() -> R~J + Send, R~J: Send,)*
+            {
+                type Output = (#(R~J,)*);
+
+                #[allow(clippy::unused_unit, non_snake_case, unused_variables)]
+                fn par_join(self) -> Self::Output {
+                    let (#(F~J,)*) = self;
+                    let mut out = (#(None::<R~J>,)*);
+
+                    rayon::scope(|s| {
+                        let (#(out~J,)*) = &mut out;
+                        #(s.spawn(move |_| *out~J = Some(F~J()));)*
+                    });
+
+                    let (#(out~J,)*) = out;
+                    (#(out~J.expect("par_join closure didn't complete"),)*)
+                }
+            }
+        });
+    };
+}
+
+#[cfg(feature = "rayon")]
+for_each_tuple!(impl_par_join);
+
+macro_rules! impl_scope_join {
+    ($n:literal) => {
+        seq!(J in 0..$n {
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<#(F~J, R~J,)*> TupleScopeJoin for (#(F~J,)*)
+            where
+                #(F~J: FnOnce() -> R~J + Send, R~J: Send,)*
+            {
+                type Output = (#(R~J,)*);
+
+                #[allow(clippy::unused_unit, non_snake_case, unused_variables)]
+                fn scope_join(self) -> Self::Output {
+                    let (#(F~J,)*) = self;
+
+                    std::thread::scope(|s| {
+                        #(let handle~J = s.spawn(F~J);)*
+                        let (#(res~J,)*) = (#(handle~J.join(),)*);
+
+                        (#(res~J.unwrap_or_else(|err| std::panic::resume_unwind(err)),)*)
+                    })
+                }
+            }
+        });
+    };
+}
+
+for_each_tuple!(impl_scope_join);
+
+macro_rules! impl_thread_join {
+    (0) => {
+        #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+        impl TupleThreadJoin for () {
+            type Output = ();
+
+            fn join_all(self) {}
+        }
+    };
+    ($n:literal) => {
+        seq!(J in 0..$n {
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<#(T~J,)*> TupleThreadJoin for (#(JoinHandle<T~J>,)*) {
+                type Output = (#(thread::Result<T~J>,)*);
+
+                #[allow(non_snake_case)]
+                fn join_all(self) -> Self::Output {
+                    let (#(T~J,)*) = self;
+
+                    (#(T~J.join(),)*)
+                }
+            }
+
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<'scope, #(T~J,)*> TupleThreadJoin for (#(ScopedJoinHandle<'scope, T~J>,)*) {
+                type Output = (#(thread::Result<T~J>,)*);
+
+                #[allow(non_snake_case)]
+                fn join_all(self) -> Self::Output {
+                    let (#(T~J,)*) = self;
+
+                    (#(T~J.join(),)*)
+                }
+            }
+        });
+    };
+}
+
+for_each_tuple!(impl_thread_join);
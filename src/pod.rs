@@ -0,0 +1,120 @@
+//! Zero-copy casts of homogeneous tuples with [`bytemuck`].
+//!
+//! Rust doesn't guarantee the layout of tuples, so they can't be [`Pod`]
+//! themselves. Instead [`PodTuple`] is a wrapper with the layout of an array,
+//! which can be converted to and from homogeneous tuples, and cast to bytes or
+//! other `Pod` types.
+use bytemuck::{Pod, PodCastError, Zeroable};
+
+use super::*;
+
+/// Homogeneous tuple of `N` elements of type `T`, stored with the layout of
+/// `[T; N]`.
+/// ```rust
+/// # use tuplestructops::pod::PodTuple;
+/// let verts = [PodTuple::from((1.0f32, 2.0)), PodTuple::from((3.0, 4.0))];
+/// let floats: &[f32] = bytemuck::cast_slice(&verts);
+/// assert_eq!(floats, &[1.0, 2.0, 3.0, 4.0]);
+/// assert_eq!(<(f32, f32)>::from(verts[1]), (3.0, 4.0));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[repr(transparent)]
+pub struct PodTuple<T, const N: usize>(pub [T; N]);
+
+// Safety: `PodTuple` is a transparent wrapper around `[T; N]`, which is
+// `Zeroable` and `Pod` if `T` is.
+unsafe impl<T: Zeroable, const N: usize> Zeroable for PodTuple<T, N> {}
+unsafe impl<T: Pod, const N: usize> Pod for PodTuple<T, N> {}
+
+impl<T, const N: usize> PodTuple<T, N> {
+    /// Underlying array.
+    pub fn as_array(&self) -> &[T; N] {
+        &self.0
+    }
+
+    /// Underlying array, mutably.
+    pub fn as_array_mut(&mut self) -> &mut [T; N] {
+        &mut self.0
+    }
+
+    /// Unwrap the underlying array.
+    pub fn into_array(self) -> [T; N] {
+        self.0
+    }
+}
+
+impl<T: Pod, const N: usize> PodTuple<T, N> {
+    /// View the elements as bytes.
+    /// ```rust
+    /// # use tuplestructops::pod::PodTuple;
+    /// let t = PodTuple::from((1u8, 2, 3));
+    /// assert_eq!(t.as_bytes(), &[1, 2, 3]);
+    /// ```
+    pub fn as_bytes(&self) -> &[u8] {
+        bytemuck::bytes_of(self)
+    }
+
+    /// View bytes as a `PodTuple`. Fails if `bytes` has the wrong length or
+    /// alignment.
+    /// ```rust
+    /// # use tuplestructops::pod::PodTuple;
+    /// let t = PodTuple::<u8, 2>::try_from_bytes(&[1, 2]).unwrap();
+    /// assert_eq!(<(u8, u8)>::from(*t), (1, 2));
+    /// assert!(PodTuple::<u8, 2>::try_from_bytes(&[1, 2, 3]).is_err());
+    /// ```
+    pub fn try_from_bytes(bytes: &[u8]) -> Result<&Self, PodCastError> {
+        bytemuck::try_from_bytes(bytes)
+    }
+
+    /// View bytes as a mutable `PodTuple`. Fails if `bytes` has the wrong
+    /// length or alignment.
+    pub fn try_from_bytes_mut(bytes: &mut [u8]) -> Result<&mut Self, PodCastError> {
+        bytemuck::try_from_bytes_mut(bytes)
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for PodTuple<T, N> {
+    fn from(array: [T; N]) -> Self {
+        PodTuple(array)
+    }
+}
+
+impl<T, const N: usize> From<PodTuple<T, N>> for [T; N] {
+    fn from(tuple: PodTuple<T, N>) -> Self {
+        tuple.0
+    }
+}
+
+impl<T, const N: usize> AsRef<[T]> for PodTuple<T, N> {
+    fn as_ref(&self) -> &[T] {
+        &self.0
+    }
+}
+
+impl<T, const N: usize> AsMut<[T]> for PodTuple<T, N> {
+    fn as_mut(&mut self) -> &mut [T] {
+        &mut self.0
+    }
+}
+
+macro_rules! impl_pod {
+    ($n:literal) => {
+        seq!(J in 0..$n {
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<T> From<(#(T,)*)> for PodTuple<T, $n> {
+                fn from(tuple: (#(T,)*)) -> Self {
+                    PodTuple(tuple.into_array())
+                }
+            }
+
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<T> From<PodTuple<T, $n>> for (#(T,)*) {
+                fn from(tuple: PodTuple<T, $n>) -> Self {
+                    Self::from_array(tuple.0)
+                }
+            }
+        });
+    };
+}
+
+for_each_tuple!(impl_pod);
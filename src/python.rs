@@ -0,0 +1,87 @@
+//! Conversions between tuples of any size and Python tuples with
+//! [`pyo3`].
+//!
+//! pyo3 only converts Rust tuples of up to 12 elements to and from Python
+//! tuples. [`PythonTuple`] wraps a tuple of any size this crate supports, and
+//! implements `IntoPyObject` and `FromPyObject` in the same way, so it can be
+//! used in the arguments and return values of `#[pyfunction]`s:
+//! ```rust,no_run
+//! # use pyo3::prelude::*;
+//! # use tuplestructops::python::PythonTuple;
+//! type Row = (u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, bool);
+//!
+//! #[pyfunction]
+//! fn first_and_flag(row: PythonTuple<Row>) -> (u8, bool) {
+//!     (row.0 .0, row.0 .15)
+//! }
+//! ```
+use std::fmt::{self, Debug, Formatter};
+
+use ::pyo3::exceptions::PyValueError;
+use ::pyo3::prelude::*;
+use ::pyo3::types::PyTuple;
+use ::pyo3::{Borrowed, IntoPyObjectExt};
+
+use super::*;
+
+/// Wrapper implementing pyo3's `IntoPyObject` and `FromPyObject` for a tuple
+/// of any size, converting to and from a Python `tuple`.
+///
+/// Unlike pyo3's own impls for tuples, the elements can't borrow from the
+/// Python tuple when extracting, so must implement `FromPyObjectOwned`.
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PythonTuple<T>(pub T);
+
+impl<T> PythonTuple<T> {
+    /// Unwrap the tuple.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T: TupleDebug> Debug for PythonTuple<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.0.fmt_debug(f)
+    }
+}
+
+macro_rules! impl_python {
+    ($n:literal) => {
+        seq!(J in 0..$n {
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<'py, #(T~J: IntoPyObject<'py>,)*> IntoPyObject<'py> for PythonTuple<(#(T~J,)*)> {
+                type Target = PyTuple;
+                type Output = Bound<'py, PyTuple>;
+                type Error = PyErr;
+
+                fn into_pyobject(self, py: Python<'py>) -> PyResult<Self::Output> {
+                    let elems: [Bound<'py, PyAny>; $n] = [#(self.0.J.into_bound_py_any(py)?,)*];
+                    PyTuple::new(py, elems)
+                }
+            }
+
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<'a, 'py, #(T~J: FromPyObjectOwned<'py>,)*> FromPyObject<'a, 'py>
+                for PythonTuple<(#(T~J,)*)>
+            {
+                type Error = PyErr;
+
+                fn extract(obj: Borrowed<'a, 'py, PyAny>) -> PyResult<Self> {
+                    let tuple = obj.cast::<PyTuple>()?;
+                    if tuple.len() != $n {
+                        return Err(PyValueError::new_err(format!(
+                            "expected tuple of length {}, but got tuple of length {}",
+                            $n,
+                            tuple.len()
+                        )));
+                    }
+                    Ok(PythonTuple((#(
+                        tuple.get_borrowed_item(J)?.extract::<T~J>().map_err(Into::into)?,
+                    )*)))
+                }
+            }
+        });
+    };
+}
+
+for_each_tuple!(impl_python);
@@ -0,0 +1,70 @@
+//! Random tuples of any size for [`quickcheck`](::quickcheck).
+//!
+//! quickcheck only implements `Arbitrary` for tuples of up to 8 elements, and
+//! the standard library only implements `Debug`, needed to report failures, for
+//! up to 12. [`ArbitraryTuple`] wraps a tuple of any size this crate supports
+//! and implements both:
+//! ```rust
+//! # use tuplestructops::quickcheck::ArbitraryTuple;
+//! type Wide = (u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, bool);
+//!
+//! fn prop(ArbitraryTuple(t): ArbitraryTuple<Wide>) -> bool {
+//!     t.0 as u32 + t.13 as u32 <= 510
+//! }
+//! quickcheck::quickcheck(prop as fn(_) -> bool);
+//! ```
+use std::fmt::{self, Debug, Formatter};
+
+use ::quickcheck::{Arbitrary, Gen};
+
+use super::*;
+
+/// Wrapper implementing quickcheck's `Arbitrary` and `Debug` for a tuple of any
+/// size.
+///
+/// Shrinking shrinks each element in turn, keeping the others unchanged, in
+/// the same way as quickcheck's own impls for tuples.
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ArbitraryTuple<T>(pub T);
+
+impl<T> ArbitraryTuple<T> {
+    /// Unwrap the tuple.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T: TupleDebug> Debug for ArbitraryTuple<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.0.fmt_debug(f)
+    }
+}
+
+macro_rules! impl_quickcheck {
+    ($n:literal) => {
+        seq!(J in 0..$n {
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<#(T~J: Arbitrary,)*> Arbitrary for ArbitraryTuple<(#(T~J,)*)> {
+                #[allow(unused_variables)]
+                fn arbitrary(g: &mut Gen) -> Self {
+                    ArbitraryTuple((#(T~J::arbitrary(g),)*))
+                }
+
+                fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+                    let iter = std::iter::empty();
+                    #(
+                        let tuple = self.clone();
+                        let iter = iter.chain(self.0.J.shrink().map(move |elem| {
+                            let mut shrunk = tuple.clone();
+                            shrunk.0.J = elem;
+                            shrunk
+                        }));
+                    )*
+                    Box::new(iter)
+                }
+            }
+        });
+    };
+}
+
+for_each_tuple!(impl_quickcheck);
@@ -0,0 +1,72 @@
+//! Random sampling of tuples of any size with [`rand`](::rand).
+//!
+//! rand only implements `Distribution` for tuples of up to 12 elements, and
+//! its traits and `StandardUniform` are both foreign to this crate, so the
+//! impls can't be extended directly. Instead [`StandardTuple`] samples every
+//! element from `StandardUniform`, and [`TupleDistribution`] samples each
+//! element from its own distribution.
+//! ```rust
+//! # use rand::{distr::Uniform, rngs::SmallRng, RngExt, SeedableRng};
+//! # use tuplestructops::rand::{random_tuple, TupleDistribution};
+//! let mut rng = SmallRng::seed_from_u64(1);
+//!
+//! type Wide = (u8, u16, u32, u64, i8, i16, i32, i64, bool, char, f32, f64, u8, u8);
+//! let _wide: Wide = random_tuple(&mut rng);
+//!
+//! let dist = TupleDistribution((Uniform::new(0, 10).unwrap(), Uniform::new(0.0, 1.0).unwrap()));
+//! let (a, b): (u32, f64) = rng.sample(dist);
+//! assert!(a < 10 && b < 1.0);
+//! ```
+use ::rand::distr::{Distribution, StandardUniform};
+use ::rand::Rng;
+
+use super::*;
+
+/// Distribution sampling each element of a tuple of any size from
+/// `StandardUniform`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct StandardTuple;
+
+/// Distribution sampling each element of a tuple from the corresponding
+/// distribution in a tuple of distributions.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TupleDistribution<D>(pub D);
+
+/// Generate a tuple of any size with each element sampled from
+/// `StandardUniform`. See [`StandardTuple`].
+pub fn random_tuple<T, R>(rng: &mut R) -> T
+where
+    StandardTuple: Distribution<T>,
+    R: Rng + ?Sized,
+{
+    StandardTuple.sample(rng)
+}
+
+macro_rules! impl_rand {
+    ($n:literal) => {
+        seq!(J in 0..$n {
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<#(T~J,)*> Distribution<(#(T~J,)*)> for StandardTuple
+            where
+                #(StandardUniform: Distribution<T~J>,)*
+            {
+                #[allow(unused_variables, clippy::unused_unit)]
+                fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> (#(T~J,)*) {
+                    (#(StandardUniform.sample(rng),)*)
+                }
+            }
+
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<#(D~J: Distribution<T~J>, T~J,)*> Distribution<(#(T~J,)*)>
+                for TupleDistribution<(#(D~J,)*)>
+            {
+                #[allow(unused_variables, clippy::unused_unit)]
+                fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> (#(T~J,)*) {
+                    (#(self.0.J.sample(rng),)*)
+                }
+            }
+        });
+    };
+}
+
+for_each_tuple!(impl_rand);
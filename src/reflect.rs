@@ -0,0 +1,96 @@
+use std::any::{type_name, TypeId};
+use std::mem::{align_of, offset_of, size_of};
+
+use super::*;
+
+/// Memory layout of a tuple's elements.
+pub trait TupleLayout: seal::Sealed {
+    /// Array of `(offset, size, align)` for each element, in bytes, as
+    /// `[(usize, usize, usize); N]`.
+    type Layout: AsRef<[(usize, usize, usize)]>;
+
+    /// Offset, size and alignment of each element, in element order. Offsets
+    /// are from the start of the tuple, and since Rust is free to reorder
+    /// tuple elements they are not necessarily increasing.
+    fn layout() -> Self::Layout;
+}
+
+/// Offset, size and alignment of each element of tuple type `T`. See
+/// [`TupleLayout::layout`].
+/// ```rust
+/// # use tuplestructops::layout_of;
+/// let layout = layout_of::<(u8, u32, u16)>();
+/// for (offset, size, align) in layout {
+///     assert_eq!(offset % align, 0);
+///     assert!(offset + size <= std::mem::size_of::<(u8, u32, u16)>());
+/// }
+/// assert_eq!(layout[1].1, 4);
+/// ```
+pub fn layout_of<T: TupleLayout>() -> T::Layout {
+    T::layout()
+}
+
+/// Names of a tuple's element types.
+pub trait TupleTypeNames: seal::Sealed {
+    /// Array of names, as `[&'static str; N]`.
+    type Names: AsRef<[&'static str]>;
+
+    /// Name of each element type, from [`std::any::type_name`]. As with that
+    /// function, these are only for diagnostics, and the exact format isn't
+    /// stable.
+    /// ```rust
+    /// # use tuplestructops::TupleTypeNames;
+    /// assert_eq!(<(u8, &str, bool)>::type_names(), ["u8", "&str", "bool"]);
+    /// ```
+    fn type_names() -> Self::Names;
+}
+
+/// [`TypeId`]s of a tuple's element types.
+pub trait TupleTypeIds: seal::Sealed {
+    /// Array of ids, as `[TypeId; N]`.
+    type Ids: AsRef<[TypeId]>;
+
+    /// `TypeId` of each element type.
+    /// ```rust
+    /// # use std::any::TypeId;
+    /// # use tuplestructops::TupleTypeIds;
+    /// let ids = <(u8, String)>::type_ids();
+    /// assert_eq!(ids, [TypeId::of::<u8>(), TypeId::of::<String>()]);
+    /// ```
+    fn type_ids() -> Self::Ids;
+}
+
+macro_rules! impl_reflect {
+    ($n:literal) => {
+        seq!(J in 0..$n {
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<#(T~J,)*> TupleLayout for (#(T~J,)*) {
+                type Layout = [(usize, usize, usize); $n];
+
+                fn layout() -> Self::Layout {
+                    [#((offset_of!(Self, J), size_of::<T~J>(), align_of::<T~J>()),)*]
+                }
+            }
+
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<#(T~J,)*> TupleTypeNames for (#(T~J,)*) {
+                type Names = [&'static str; $n];
+
+                fn type_names() -> Self::Names {
+                    [#(type_name::<T~J>(),)*]
+                }
+            }
+
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<#(T~J: 'static,)*> TupleTypeIds for (#(T~J,)*) {
+                type Ids = [TypeId; $n];
+
+                fn type_ids() -> Self::Ids {
+                    [#(TypeId::of::<T~J>(),)*]
+                }
+            }
+        });
+    };
+}
+
+for_each_tuple!(impl_reflect);
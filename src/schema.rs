@@ -0,0 +1,129 @@
+//! JSON Schemas for serialized tuples with [`schemars`].
+//!
+//! [`SerdeTuple`] and [`SerdeTupleMap`] implement [`JsonSchema`] for tuples of
+//! any size, describing the same representations they serialize to. Tuples are
+//! fixed length arrays with a schema per position, matching schemars' own
+//! impls for tuples of up to 16 elements. A field holding a bare tuple can use
+//! them with `#[schemars(with = ...)]`:
+//! ```rust
+//! # use schemars::JsonSchema;
+//! # use serde::Serialize;
+//! type Cols = (u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8);
+//!
+//! #[derive(Serialize, JsonSchema)]
+//! struct Row {
+//!     #[serde(with = "tuplestructops::serde")]
+//!     #[schemars(with = "tuplestructops::serde::SerdeTuple<Cols>")]
+//!     cols: Cols,
+//! }
+//!
+//! let schema = schemars::schema_for!(Row);
+//! assert_eq!(schema.as_value()["properties"]["cols"]["maxItems"], 16);
+//! ```
+use std::borrow::Cow;
+
+use schemars::{json_schema, JsonSchema, Schema, SchemaGenerator};
+
+use super::serde::{SerdeTuple, SerdeTupleMap};
+use super::*;
+
+/// JSON Schemas for the serialized forms of a tuple.
+pub trait TupleJsonSchema: seal::Sealed {
+    /// Name of the tuple type, built from the element schema names.
+    fn tuple_schema_name() -> Cow<'static, str>;
+
+    /// Unique identifier of the tuple type, built from the element schema ids.
+    fn tuple_schema_id() -> Cow<'static, str>;
+
+    /// Schema for the tuple as a fixed length array.
+    fn tuple_json_schema(generator: &mut SchemaGenerator) -> Schema;
+
+    /// Schema for the tuple as a map keyed by element index.
+    fn index_map_json_schema(generator: &mut SchemaGenerator) -> Schema;
+}
+
+impl<T: TupleJsonSchema> JsonSchema for SerdeTuple<T> {
+    fn inline_schema() -> bool {
+        true
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        T::tuple_schema_name()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        T::tuple_schema_id()
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        T::tuple_json_schema(generator)
+    }
+}
+
+impl<T: TupleJsonSchema> JsonSchema for SerdeTupleMap<T> {
+    fn inline_schema() -> bool {
+        true
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        format!("IndexMap_of_{}", T::tuple_schema_name()).into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        format!("IndexMap{}", T::tuple_schema_id()).into()
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        T::index_map_json_schema(generator)
+    }
+}
+
+macro_rules! impl_schema {
+    ($n:literal) => {
+        seq!(J in 0..$n {
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<#(T~J: JsonSchema,)*> TupleJsonSchema for (#(T~J,)*) {
+                fn tuple_schema_name() -> Cow<'static, str> {
+                    let names: [Cow<'static, str>; $n] = [#(T~J::schema_name(),)*];
+                    format!("Tuple_of_{}", names.join("_and_")).into()
+                }
+
+                fn tuple_schema_id() -> Cow<'static, str> {
+                    let ids: [Cow<'static, str>; $n] = [#(T~J::schema_id(),)*];
+                    format!("({})", ids.join(",")).into()
+                }
+
+                #[allow(unused_variables)]
+                fn tuple_json_schema(generator: &mut SchemaGenerator) -> Schema {
+                    let items: [Schema; $n] = [#(generator.subschema_for::<T~J>(),)*];
+                    json_schema!({
+                        "type": "array",
+                        "prefixItems": items,
+                        "minItems": $n,
+                        "maxItems": $n,
+                    })
+                }
+
+                #[allow(unused_mut, unused_variables)]
+                fn index_map_json_schema(generator: &mut SchemaGenerator) -> Schema {
+                    // Build the properties as a schema object, since it's
+                    // keyed by strings like a schema.
+                    let mut properties = json_schema!({});
+                    #(properties.insert(
+                        stringify!(J).to_string(),
+                        generator.subschema_for::<T~J>().to_value(),
+                    );)*
+                    let required: [&str; $n] = [#(stringify!(J),)*];
+                    json_schema!({
+                        "type": "object",
+                        "properties": properties,
+                        "required": required,
+                        "additionalProperties": false,
+                    })
+                }
+            }
+        });
+    };
+}
+
+for_each_tuple!(impl_schema);
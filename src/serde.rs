@@ -0,0 +1,284 @@
+//! Serialization of tuples of any size with [`serde`](::serde).
+//!
+//! serde only implements `Serialize` and `Deserialize` for tuples of up to 16
+//! elements. [`TupleSerialize`] and [`TupleDeserialize`] cover every tuple
+//! size this crate supports, including up to 32 with the `tuple_32` feature,
+//! using the same representation as serde (a fixed length sequence). They can
+//! be used through the [`SerdeTuple`] wrapper, or with the [`serialize`] and
+//! [`deserialize`] functions in a field attribute:
+//! ```rust
+//! # use serde::{Deserialize, Serialize};
+//! #[derive(Serialize, Deserialize)]
+//! struct Row {
+//!     #[serde(with = "tuplestructops::serde")]
+//!     cols: (u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8),
+//! }
+//! ```
+//!
+//! Alternatively, the [`map`] module and the [`SerdeTupleMap`] wrapper encode a
+//! tuple as a map keyed by element index, such as `{"0": .., "1": ..}`.
+use std::fmt;
+use std::marker::PhantomData;
+
+use ::serde::de::{self, Deserialize, Deserializer, MapAccess, SeqAccess, Visitor};
+use ::serde::ser::{Serialize, SerializeMap, SerializeTuple, Serializer};
+
+use super::*;
+
+/// Serialize a tuple as a fixed length sequence.
+pub trait TupleSerialize: seal::Sealed {
+    /// Serialize each element in order with `serializer.serialize_tuple`.
+    fn serialize_tuple<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>;
+
+    /// Serialize as a map from each element's index, as a string, to the
+    /// element.
+    fn serialize_index_map<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>;
+}
+
+/// Deserialize a tuple from a fixed length sequence.
+pub trait TupleDeserialize<'de>: seal::Sealed + Sized {
+    /// Deserialize each element in order with `deserializer.deserialize_tuple`.
+    /// The sequence must have exactly as many elements as the tuple.
+    fn deserialize_tuple<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>;
+
+    /// Deserialize from a map keyed by element index, as produced by
+    /// [`TupleSerialize::serialize_index_map`]. Keys may be strings or
+    /// integers, in any order, and every index must be present exactly once.
+    fn deserialize_index_map<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>;
+}
+
+/// Serialize a tuple of any size. This is intended for use with
+/// `#[serde(serialize_with = "tuplestructops::serde::serialize")]`.
+pub fn serialize<T, S>(tuple: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: TupleSerialize,
+    S: Serializer,
+{
+    tuple.serialize_tuple(serializer)
+}
+
+/// Deserialize a tuple of any size. This is intended for use with
+/// `#[serde(deserialize_with = "tuplestructops::serde::deserialize")]`.
+pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    T: TupleDeserialize<'de>,
+    D: Deserializer<'de>,
+{
+    T::deserialize_tuple(deserializer)
+}
+
+/// Wrapper which implements `Serialize` and `Deserialize` for a tuple of any
+/// size.
+/// ```rust
+/// # use tuplestructops::serde::SerdeTuple;
+/// let t = SerdeTuple((1, "two", 3.0));
+/// let json = serde_json::to_string(&t).unwrap();
+/// assert_eq!(json, r#"[1,"two",3.0]"#);
+///
+/// let SerdeTuple(back): SerdeTuple<(i32, String, f64)> = serde_json::from_str(&json).unwrap();
+/// assert_eq!(back, (1, "two".to_string(), 3.0));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SerdeTuple<T>(pub T);
+
+impl<T: TupleSerialize> Serialize for SerdeTuple<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize_tuple(serializer)
+    }
+}
+
+impl<'de, T: TupleDeserialize<'de>> Deserialize<'de> for SerdeTuple<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        T::deserialize_tuple(deserializer).map(SerdeTuple)
+    }
+}
+
+/// Serialize and deserialize tuples as maps keyed by element index.
+///
+/// This is intended for use with `#[serde(with = "tuplestructops::serde::map")]`.
+/// ```rust
+/// # use serde::{Deserialize, Serialize};
+/// #[derive(Serialize, Deserialize)]
+/// struct Sample {
+///     #[serde(with = "tuplestructops::serde::map")]
+///     pos: (i32, i32, bool),
+/// }
+///
+/// let json = serde_json::to_string(&Sample { pos: (1, 2, true) }).unwrap();
+/// assert_eq!(json, r#"{"pos":{"0":1,"1":2,"2":true}}"#);
+///
+/// let s: Sample = serde_json::from_str(r#"{"pos":{"2":false,"0":3,"1":4}}"#).unwrap();
+/// assert_eq!(s.pos, (3, 4, false));
+/// ```
+pub mod map {
+    use super::*;
+
+    /// Serialize a tuple as a map keyed by element index.
+    pub fn serialize<T, S>(tuple: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: TupleSerialize,
+        S: Serializer,
+    {
+        tuple.serialize_index_map(serializer)
+    }
+
+    /// Deserialize a tuple from a map keyed by element index.
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: TupleDeserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        T::deserialize_index_map(deserializer)
+    }
+}
+
+/// Wrapper which implements `Serialize` and `Deserialize` for a tuple of any
+/// size as a map keyed by element index. See [`map`].
+/// ```rust
+/// # use tuplestructops::serde::SerdeTupleMap;
+/// let json = serde_json::to_string(&SerdeTupleMap(("a", 1))).unwrap();
+/// assert_eq!(json, r#"{"0":"a","1":1}"#);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SerdeTupleMap<T>(pub T);
+
+impl<T: TupleSerialize> Serialize for SerdeTupleMap<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize_index_map(serializer)
+    }
+}
+
+impl<'de, T: TupleDeserialize<'de>> Deserialize<'de> for SerdeTupleMap<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        T::deserialize_index_map(deserializer).map(SerdeTupleMap)
+    }
+}
+
+struct TupleVisitor<T>(PhantomData<fn() -> T>);
+
+struct IndexMapVisitor<T>(PhantomData<fn() -> T>);
+
+// Map key holding an element index, which may be encoded as a string or an
+// integer.
+struct Index(u64);
+
+impl<'de> Deserialize<'de> for Index {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct IndexVisitor;
+
+        impl Visitor<'_> for IndexVisitor {
+            type Value = Index;
+
+            fn expecting(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+                fmt.write_str("a tuple element index")
+            }
+
+            fn visit_u64<E: de::Error>(self, v: u64) -> Result<Index, E> {
+                Ok(Index(v))
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<Index, E> {
+                v.parse()
+                    .map(Index)
+                    .map_err(|_| E::invalid_value(de::Unexpected::Str(v), &self))
+            }
+        }
+
+        deserializer.deserialize_identifier(IndexVisitor)
+    }
+}
+
+macro_rules! impl_serde {
+    ($n:literal) => {
+        seq!(J in 0..$n {
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<#(T~J: Serialize,)*> TupleSerialize for (#(T~J,)*) {
+                fn serialize_tuple<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    #[allow(unused_mut)]
+                    let mut tup = serializer.serialize_tuple($n)?;
+                    #(tup.serialize_element(&self.J)?;)*
+                    tup.end()
+                }
+
+                fn serialize_index_map<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    #[allow(unused_mut)]
+                    let mut map = serializer.serialize_map(Some($n))?;
+                    #(map.serialize_entry(stringify!(J), &self.J)?;)*
+                    map.end()
+                }
+            }
+
+            impl<'de, #(T~J: Deserialize<'de>,)*> Visitor<'de> for TupleVisitor<(#(T~J,)*)> {
+                type Value = (#(T~J,)*);
+
+                fn expecting(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+                    write!(fmt, "a tuple of size {}", $n)
+                }
+
+                #[allow(unused_mut, unused_variables)]
+                fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                    let tuple = (#(
+                        seq.next_element()?
+                            .ok_or_else(|| de::Error::invalid_length(J, &self))?,
+                    )*);
+
+                    let mut len = $n;
+                    while seq.next_element::<de::IgnoredAny>()?.is_some() {
+                        len += 1;
+                    }
+                    if len != $n {
+                        return Err(de::Error::invalid_length(len, &self));
+                    }
+                    Ok(tuple)
+                }
+            }
+
+            impl<'de, #(T~J: Deserialize<'de>,)*> Visitor<'de> for IndexMapVisitor<(#(T~J,)*)> {
+                type Value = (#(T~J,)*);
+
+                fn expecting(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+                    write!(fmt, "a map of {} tuple elements keyed by index", $n)
+                }
+
+                #[allow(unused_mut, non_snake_case)]
+                fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+                    #(let mut T~J: Option<T~J> = None;)*
+
+                    while let Some(Index(idx)) = map.next_key()? {
+                        match idx {
+                            #(
+                                J => {
+                                    if T~J.is_some() {
+                                        return Err(de::Error::duplicate_field(stringify!(J)));
+                                    }
+                                    T~J = Some(map.next_value()?);
+                                }
+                            )*
+                            _ => {
+                                return Err(de::Error::invalid_value(
+                                    de::Unexpected::Unsigned(idx),
+                                    &self,
+                                ))
+                            }
+                        }
+                    }
+
+                    Ok((#(T~J.ok_or_else(|| de::Error::missing_field(stringify!(J)))?,)*))
+                }
+            }
+
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<'de, #(T~J: Deserialize<'de>,)*> TupleDeserialize<'de> for (#(T~J,)*) {
+                fn deserialize_tuple<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    deserializer.deserialize_tuple($n, TupleVisitor::<Self>(PhantomData))
+                }
+
+                fn deserialize_index_map<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    deserializer.deserialize_map(IndexMapVisitor::<Self>(PhantomData))
+                }
+            }
+        });
+    };
+}
+
+for_each_tuple!(impl_serde);
@@ -0,0 +1,58 @@
+//! Conversion between homogeneous tuples and [`Simd`] vectors.
+//!
+//! This requires a nightly compiler and the `portable_simd` feature.
+use std::simd::{Simd, SimdElement};
+
+use super::*;
+
+/// Conversion between a homogeneous tuple and a [`Simd`] vector with one lane
+/// per element. This is implemented for all non-empty tuple arities.
+pub trait TupleSimd<T: SimdElement>: HomogeneousTuple<T> {
+    /// Vector type with the same number of lanes as the tuple has elements,
+    /// ie `Simd<T, N>`.
+    type Simd;
+
+    /// Convert the tuple into a vector.
+    /// ```rust
+    /// #![feature(portable_simd)]
+    /// # use tuplestructops::TupleSimd;
+    /// use std::simd::Simd;
+    ///
+    /// let v = (1i32, 2, 3, 4).into_simd() * Simd::splat(10);
+    /// assert_eq!(v.to_array(), [10, 20, 30, 40]);
+    /// ```
+    fn into_simd(self) -> Self::Simd;
+
+    /// Convert a vector into a tuple.
+    /// ```rust
+    /// #![feature(portable_simd)]
+    /// # use tuplestructops::TupleSimd;
+    /// use std::simd::Simd;
+    ///
+    /// let t: (_, _) = TupleSimd::from_simd(Simd::from_array([1.5, 2.5]));
+    /// assert_eq!(t, (1.5, 2.5));
+    /// ```
+    fn from_simd(simd: Self::Simd) -> Self;
+}
+
+macro_rules! impl_simd {
+    (0) => {};
+    ($n:literal) => {
+        seq!(J in 0..$n {
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<T: SimdElement> TupleSimd<T> for (#(T,)*) {
+                type Simd = Simd<T, $n>;
+
+                fn into_simd(self) -> Self::Simd {
+                    Simd::from_array(self.into_array())
+                }
+
+                fn from_simd(simd: Self::Simd) -> Self {
+                    Self::from_array(simd.to_array())
+                }
+            }
+        });
+    };
+}
+
+for_each_tuple!(impl_simd);
@@ -0,0 +1,56 @@
+//! Conversions between homogeneous tuples and [`SmallVec`]s.
+//!
+//! A tuple converts into a `SmallVec` with exactly enough inline capacity for
+//! its elements, so it stays on the stack while gaining `Vec`-like methods.
+//! Converting back checks the length:
+//! ```rust
+//! # use tuplestructops::smallvec::TupleSmallVec;
+//! let mut v = (1, 2, 3).into_smallvec();
+//! assert!(!v.spilled());
+//! v.retain(|x| *x != 2);
+//! v.insert(0, 0);
+//! assert_eq!(<(i32, i32, i32)>::from_smallvec(v), Ok((0, 1, 3)));
+//! ```
+use ::smallvec::{Array, SmallVec};
+
+use super::homogeneous::LengthError;
+use super::*;
+
+/// Conversion between homogeneous tuples and `SmallVec`s.
+pub trait TupleSmallVec<T>: HomogeneousTuple<T> {
+    /// Inline storage which exactly fits the tuple's elements, `[T; N]`.
+    type Inline: Array<Item = T>;
+
+    /// Move the elements into a `SmallVec`, stored inline.
+    fn into_smallvec(self) -> SmallVec<Self::Inline>;
+
+    /// Move the elements of a `SmallVec` of any inline capacity into a tuple,
+    /// failing if it's not the same length as the tuple.
+    fn from_smallvec<A: Array<Item = T>>(vec: SmallVec<A>) -> Result<Self, LengthError>;
+}
+
+macro_rules! impl_smallvec {
+    ($n:literal) => {
+        seq!(J in 0..$n {
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<T> TupleSmallVec<T> for (#(T,)*) {
+                type Inline = [T; $n];
+
+                fn into_smallvec(self) -> SmallVec<Self::Inline> {
+                    SmallVec::from_buf(self.into_array())
+                }
+
+                #[allow(unused_mut, unused_variables, clippy::unused_unit)]
+                fn from_smallvec<A: Array<Item = T>>(vec: SmallVec<A>) -> Result<Self, LengthError> {
+                    if vec.len() != $n {
+                        return Err(LengthError::new($n, vec.len()));
+                    }
+                    let mut elems = vec.into_iter();
+                    Ok((#(elems.next().unwrap(),)*))
+                }
+            }
+        });
+    };
+}
+
+for_each_tuple!(impl_smallvec);
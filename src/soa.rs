@@ -0,0 +1,65 @@
+use super::*;
+
+/// Conversion from an array of tuples to a tuple of arrays, ie from
+/// "array-of-structs" to "struct-of-arrays" layout.
+pub trait ArrayOfTuples: seal::Sealed {
+    /// Tuple of arrays.
+    type Output;
+
+    /// Convert `[(A, B, ...); N]` into `([A; N], [B; N], ...)`.
+    /// ```rust
+    /// # use tuplestructops::ArrayOfTuples;
+    /// let points = [(1, 'a'), (2, 'b'), (3, 'c')];
+    /// assert_eq!(points.into_soa(), ([1, 2, 3], ['a', 'b', 'c']));
+    /// ```
+    fn into_soa(self) -> Self::Output;
+}
+
+/// Conversion from a tuple of arrays to an array of tuples, ie from
+/// "struct-of-arrays" to "array-of-structs" layout.
+pub trait TupleOfArrays: seal::Sealed {
+    /// Array of tuples.
+    type Output;
+
+    /// Convert `([A; N], [B; N], ...)` into `[(A, B, ...); N]`.
+    /// ```rust
+    /// # use tuplestructops::TupleOfArrays;
+    /// let columns = ([1, 2, 3], ['a', 'b', 'c']);
+    /// assert_eq!(columns.into_aos(), [(1, 'a'), (2, 'b'), (3, 'c')]);
+    /// ```
+    fn into_aos(self) -> Self::Output;
+}
+
+macro_rules! impl_soa {
+    (0) => {};
+    ($n:literal) => {
+        seq!(J in 0..$n {
+            impl<const N: usize, #(T~J,)*> seal::Sealed for [(#(T~J,)*); N] {}
+
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<const N: usize, #(T~J,)*> ArrayOfTuples for [(#(T~J,)*); N] {
+                type Output = (#([T~J; N],)*);
+
+                fn into_soa(self) -> Self::Output {
+                    let mut elems = self.map(|(#(t~J,)*)| (#(Some(t~J),)*));
+
+                    (#(std::array::from_fn(|i| elems[i].J.take().unwrap()),)*)
+                }
+            }
+
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<const N: usize, #(T~J,)*> TupleOfArrays for (#([T~J; N],)*) {
+                type Output = [(#(T~J,)*); N];
+
+                fn into_aos(self) -> Self::Output {
+                    let (#(t~J,)*) = self;
+                    #(let mut t~J = t~J.into_iter();)*
+
+                    std::array::from_fn(|_| (#(t~J.next().unwrap(),)*))
+                }
+            }
+        });
+    };
+}
+
+for_each_tuple!(impl_soa);
@@ -0,0 +1,149 @@
+//! Property testing strategies for tuples of any size with [`proptest`].
+//!
+//! proptest only implements `Strategy` and `Arbitrary` for tuples of up to 12
+//! elements, and requires generated values to implement `Debug`, which the
+//! standard library also limits to 12 elements. [`TupleStrategy`] combines a
+//! tuple of strategies of any size, generating [`TupleValue`]s which wrap the
+//! generated tuple with a `Debug` impl. Shrinking simplifies each element in
+//! turn, from left to right.
+//! ```rust
+//! # use proptest::prelude::*;
+//! # use tuplestructops::strategy::{TupleStrategy, TupleValue};
+//! let wide = TupleStrategy((
+//!     0..10u8, 0..10u8, 0..10u8, 0..10u8, 0..10u8, 0..10u8, 0..10u8,
+//!     0..10u8, 0..10u8, 0..10u8, 0..10u8, 0..10u8, 0..10u8, any::<bool>(),
+//! ));
+//!
+//! proptest!(|(TupleValue(t) in wide)| {
+//!     prop_assert!(t.0 < 10 && t.12 < 10);
+//! });
+//! ```
+//! `TupleValue` also implements `Arbitrary` when all its elements do, so
+//! `any::<TupleValue<(A, B, ...)>>()` is a strategy for arbitrary tuples.
+use std::fmt::{self, Debug, Formatter};
+
+use proptest::arbitrary::{any, Arbitrary};
+use proptest::strategy::{NewTree, Strategy, ValueTree};
+use proptest::test_runner::TestRunner;
+
+use super::*;
+
+/// Strategy generating tuples from a tuple of strategies of any size.
+#[derive(Clone, Copy)]
+pub struct TupleStrategy<T>(pub T);
+
+impl<T: TupleDebug> Debug for TupleStrategy<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("TupleStrategy")
+            .field(&self.0.debug())
+            .finish()
+    }
+}
+
+/// A tuple generated by [`TupleStrategy`], which implements `Debug` for tuples
+/// of any size.
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TupleValue<T>(pub T);
+
+impl<T> TupleValue<T> {
+    /// Unwrap the generated tuple.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T: TupleDebug> Debug for TupleValue<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.0.fmt_debug(f)
+    }
+}
+
+/// Value tree for [`TupleStrategy`], which shrinks each element in turn.
+#[derive(Clone, Copy)]
+pub struct TupleValueTree<T> {
+    trees: T,
+    shrinker: usize,
+    prev_shrinker: Option<usize>,
+}
+
+impl<T: TupleDebug> Debug for TupleValueTree<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TupleValueTree")
+            .field("trees", &self.trees.debug())
+            .field("shrinker", &self.shrinker)
+            .field("prev_shrinker", &self.prev_shrinker)
+            .finish()
+    }
+}
+
+macro_rules! impl_strategy {
+    ($n:literal) => {
+        seq!(J in 0..$n {
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<#(T~J: Strategy,)*> Strategy for TupleStrategy<(#(T~J,)*)> {
+                type Tree = TupleValueTree<(#(T~J::Tree,)*)>;
+                type Value = TupleValue<(#(T~J::Value,)*)>;
+
+                #[allow(unused_variables)]
+                fn new_tree(&self, runner: &mut TestRunner) -> NewTree<Self> {
+                    Ok(TupleValueTree {
+                        trees: (#(self.0.J.new_tree(runner)?,)*),
+                        shrinker: 0,
+                        prev_shrinker: None,
+                    })
+                }
+            }
+
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<#(T~J: ValueTree,)*> ValueTree for TupleValueTree<(#(T~J,)*)> {
+                type Value = TupleValue<(#(T~J::Value,)*)>;
+
+                fn current(&self) -> Self::Value {
+                    TupleValue((#(self.trees.J.current(),)*))
+                }
+
+                fn simplify(&mut self) -> bool {
+                    #(
+                        if self.shrinker == J {
+                            if self.trees.J.simplify() {
+                                self.prev_shrinker = Some(J);
+                                return true;
+                            }
+                            self.shrinker += 1;
+                        }
+                    )*
+                    false
+                }
+
+                fn complicate(&mut self) -> bool {
+                    match self.prev_shrinker {
+                        #(
+                            Some(J) => {
+                                if self.trees.J.complicate() {
+                                    self.shrinker = J;
+                                    true
+                                } else {
+                                    self.prev_shrinker = None;
+                                    false
+                                }
+                            }
+                        )*
+                        _ => false,
+                    }
+                }
+            }
+
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<#(T~J: Arbitrary,)*> Arbitrary for TupleValue<(#(T~J,)*)> {
+                type Parameters = ();
+                type Strategy = TupleStrategy<(#(T~J::Strategy,)*)>;
+
+                fn arbitrary_with((): ()) -> Self::Strategy {
+                    TupleStrategy((#(any::<T~J>(),)*))
+                }
+            }
+        });
+    };
+}
+
+for_each_tuple!(impl_strategy);
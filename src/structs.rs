@@ -0,0 +1,205 @@
+use super::*;
+
+/// Conversion between a struct and the tuple of its fields, usually
+/// implemented with `#[derive(TupleOps)]` (`derive` feature).
+///
+/// This lets structs take part in tuple operations by converting to and from
+/// their tuple form. Unlike the other traits in this crate, it isn't sealed,
+/// so it can be implemented by hand too.
+/// ```rust
+/// # use tuplestructops::TupleOps;
+/// #[derive(Debug, PartialEq)]
+/// struct Point {
+///     x: i32,
+///     y: i32,
+/// }
+///
+/// // Equivalent to #[derive(TupleOps)]
+/// impl TupleOps for Point {
+///     type Tuple = (i32, i32);
+///
+///     fn into_tuple(self) -> (i32, i32) {
+///         (self.x, self.y)
+///     }
+///
+///     fn from_tuple((x, y): (i32, i32)) -> Self {
+///         Point { x, y }
+///     }
+/// }
+///
+/// let p = Point { x: 1, y: 2 };
+/// assert_eq!(p.idx::<1>(), 2);
+/// assert_eq!(Point { x: 1, y: 2 }.join(('z',)), (1, 2, 'z'));
+///
+/// let (x, y) = Point { x: 1, y: 2 }.into_tuple();
+/// assert_eq!(Point::from_tuple((y, x)), Point { x: 2, y: 1 });
+/// ```
+pub trait TupleOps: Sized {
+    /// Tuple of the field types, in declaration order.
+    type Tuple;
+
+    /// Move the fields into a tuple.
+    fn into_tuple(self) -> Self::Tuple;
+
+    /// Construct from a tuple of the fields.
+    fn from_tuple(tuple: Self::Tuple) -> Self;
+
+    /// Join the fields with another tuple. See [`TupleJoin::join`].
+    fn join<RHS>(self, other: RHS) -> TupleJoinOutput<Self::Tuple, RHS>
+    where
+        Self::Tuple: TupleJoin<RHS>,
+    {
+        self.into_tuple().join(other)
+    }
+
+    /// Split the fields into two tuples. See [`TupleSplit::split`].
+    fn split<LHS, RHS>(self) -> (LHS, RHS)
+    where
+        Self::Tuple: TupleSplit<LHS, RHS>,
+    {
+        self.into_tuple().split()
+    }
+
+    /// Return field `I`, by position. See [`TupleIdx::idx`].
+    fn idx<const I: usize>(self) -> <Self::Tuple as TupleIdx<I>>::Output
+    where
+        Self::Tuple: TupleIdx<I>,
+    {
+        self.into_tuple().idx()
+    }
+}
+
+/// A newtype wrapper around a tuple, usually implemented with
+/// `#[derive(TupleLike)]` (`derive` feature), which also implements
+/// [`TupleOps`] with the inner tuple as [`TupleOps::Tuple`].
+///
+/// This gives by-reference access to the inner tuple, so that operations on
+/// tuple references like [`TupleIdx`] also work on the wrapper.
+/// ```rust
+/// # use tuplestructops::{TupleIdx, TupleLike, TupleOps};
+/// struct Row(pub (u32, String, bool));
+///
+/// // Equivalent to #[derive(TupleLike)]
+/// impl TupleOps for Row {
+///     type Tuple = (u32, String, bool);
+///
+///     fn into_tuple(self) -> Self::Tuple {
+///         self.0
+///     }
+///
+///     fn from_tuple(tuple: Self::Tuple) -> Self {
+///         Row(tuple)
+///     }
+/// }
+///
+/// impl TupleLike for Row {
+///     fn as_tuple(&self) -> &Self::Tuple {
+///         &self.0
+///     }
+///
+///     fn as_tuple_mut(&mut self) -> &mut Self::Tuple {
+///         &mut self.0
+///     }
+/// }
+///
+/// let mut row = Row((1, String::from("a"), true));
+/// row.as_tuple_mut().1.push('b');
+/// assert_eq!(TupleIdx::<1>::idx(row.as_tuple()), "ab");
+/// assert_eq!(row.idx::<0>(), 1);
+/// ```
+pub trait TupleLike: TupleOps {
+    /// Reference to the inner tuple.
+    fn as_tuple(&self) -> &Self::Tuple;
+
+    /// Mutable reference to the inner tuple.
+    fn as_tuple_mut(&mut self) -> &mut Self::Tuple;
+}
+
+/// Define `#[repr(C)]` tuple structs with the same fields as a tuple, for
+/// passing tuple-shaped data across FFI boundaries.
+///
+/// Rust tuples have no specified layout, so can't be used in `extern`
+/// functions. Each struct has public positional fields, `From` conversions
+/// to and from the tuple, and implements [`TupleOps`] with the tuple as
+/// [`TupleOps::Tuple`]. Attributes such as derives are passed through to the
+/// struct. Structs can have up to 32 fields.
+/// ```rust
+/// # use tuplestructops::{repr_c_tuple, TupleOps};
+/// repr_c_tuple! {
+///     /// A sample, as passed to C.
+///     #[derive(Debug, Clone, Copy, PartialEq)]
+///     pub struct Sample(u32, f64, i8);
+/// }
+///
+/// extern "C" fn record(sample: Sample) -> f64 {
+///     let (_, value, _) = sample.into();
+///     value
+/// }
+///
+/// assert_eq!(record((1, 2.5, -1).into()), 2.5);
+/// assert_eq!(Sample::from_tuple((1, 2.5, -1)).idx::<2>(), -1);
+/// ```
+#[macro_export]
+macro_rules! repr_c_tuple {
+    () => {};
+    (
+        $(#[$attr:meta])*
+        $vis:vis struct $name:ident($($ty:ty),* $(,)?);
+        $($rest:tt)*
+    ) => {
+        $crate::repr_c_tuple!(
+            @zip $(#[$attr])* $vis $name [] [$($ty,)*]
+            [
+                f0 f1 f2 f3 f4 f5 f6 f7 f8 f9 f10 f11 f12 f13 f14 f15
+                f16 f17 f18 f19 f20 f21 f22 f23 f24 f25 f26 f27 f28 f29 f30 f31
+            ]
+        );
+        $crate::repr_c_tuple!($($rest)*);
+    };
+
+    // Pair each field type with a name to bind it to, since macro_rules can't
+    // generate the field indices.
+    (@zip $(#[$attr:meta])* $vis:vis $name:ident [$($done:tt)*] [] [$($unused:ident)*]) => {
+        $crate::repr_c_tuple!(@struct $(#[$attr])* $vis $name $($done)*);
+    };
+    (
+        @zip $(#[$attr:meta])* $vis:vis $name:ident [$($done:tt)*]
+        [$ty:ty, $($tys:ty,)*] [$field:ident $($fields:ident)*]
+    ) => {
+        $crate::repr_c_tuple!(
+            @zip $(#[$attr])* $vis $name [$($done)* ($field: $ty)] [$($tys,)*] [$($fields)*]
+        );
+    };
+
+    (@struct $(#[$attr:meta])* $vis:vis $name:ident $(($field:ident: $ty:ty))*) => {
+        $(#[$attr])*
+        #[repr(C)]
+        $vis struct $name($(pub $ty,)*);
+
+        impl ::core::convert::From<($($ty,)*)> for $name {
+            #[allow(clippy::unused_unit)]
+            fn from(($($field,)*): ($($ty,)*)) -> Self {
+                $name($($field,)*)
+            }
+        }
+
+        impl ::core::convert::From<$name> for ($($ty,)*) {
+            #[allow(clippy::unused_unit)]
+            fn from($name($($field,)*): $name) -> Self {
+                ($($field,)*)
+            }
+        }
+
+        impl $crate::TupleOps for $name {
+            type Tuple = ($($ty,)*);
+
+            fn into_tuple(self) -> Self::Tuple {
+                self.into()
+            }
+
+            fn from_tuple(tuple: Self::Tuple) -> Self {
+                tuple.into()
+            }
+        }
+    };
+}
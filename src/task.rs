@@ -0,0 +1,82 @@
+//! Spawning tuples of futures as tokio tasks.
+//!
+//! This is enabled with the `tokio` feature.
+use std::future::Future;
+use std::marker::PhantomData;
+
+use futures::future::MaybeDone;
+use tokio::task::{JoinError, JoinHandle};
+
+use super::future::{PinnedTuple, TryJoinTuple};
+use super::*;
+
+/// Spawn a tuple of futures as tokio tasks.
+pub trait TupleSpawnAll: seal::Sealed {
+    /// Tuple of [`JoinHandle`]s.
+    type Handles;
+
+    /// Spawn each future onto the current tokio runtime with
+    /// [`tokio::spawn`], and return the tuple of their [`JoinHandle`]s.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called outside of a tokio runtime.
+    /// ```rust
+    /// # use tuplestructops::{TupleJoinHandles, TupleSpawnAll};
+    /// # let rt = tokio::runtime::Builder::new_current_thread().build().unwrap();
+    /// # rt.block_on(async {
+    /// let handles = (async { 1 }, async { "two" }).spawn_all();
+    /// assert_eq!(handles.join_handles().await.unwrap(), (1, "two"));
+    /// # });
+    /// ```
+    fn spawn_all(self) -> Self::Handles;
+}
+
+/// Await a tuple of tokio [`JoinHandle`]s.
+pub trait TupleJoinHandles: seal::Sealed {
+    /// Future resolving to the tuple of task outputs.
+    type Future: Future;
+
+    /// Return a future which awaits all the tasks, resolving to the tuple of
+    /// their outputs, or the first [`JoinError`] if any task panicked or was
+    /// cancelled.
+    fn join_handles(self) -> Self::Future;
+}
+
+macro_rules! impl_task {
+    ($n:literal) => {
+        seq!(J in 0..$n {
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<#(F~J,)*> TupleSpawnAll for (#(F~J,)*)
+            where
+                #(F~J: Future + Send + 'static, F~J::Output: Send + 'static,)*
+            {
+                type Handles = (#(JoinHandle<F~J::Output>,)*);
+
+                #[allow(clippy::unused_unit, non_snake_case)]
+                fn spawn_all(self) -> Self::Handles {
+                    let (#(F~J,)*) = self;
+
+                    (#(tokio::spawn(F~J),)*)
+                }
+            }
+
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<#(T~J,)*> TupleJoinHandles for (#(JoinHandle<T~J>,)*) {
+                type Future = TryJoinTuple<(#(MaybeDone<JoinHandle<T~J>>,)*), JoinError>;
+
+                #[allow(non_snake_case)]
+                fn join_handles(self) -> Self::Future {
+                    let (#(T~J,)*) = self;
+
+                    TryJoinTuple {
+                        futs: (#(MaybeDone::Future(T~J),)*).into_pinned(),
+                        _error: PhantomData,
+                    }
+                }
+            }
+        });
+    };
+}
+
+for_each_tuple!(impl_task);
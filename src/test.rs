@@ -61,3 +61,2670 @@ fn boundaries() {
             seq!(I in 0..16 { (#(I,)*) }).join(seq!(J in 0..16 { (#(J,)*) }));
     }
 }
+
+#[test]
+fn convert() {
+    let out: (String, u64, f64) = ("a", 1u8, 2.5f32).convert();
+    assert_eq!(out, ("a".to_string(), 1, 2.5));
+
+    let () = ().convert();
+}
+
+#[test]
+fn try_convert() {
+    use convert::TryConvertError3;
+
+    let out: Result<(u8, i8, u16), _> = (1u32, -2i64, 3i32).try_convert();
+    assert_eq!(out, Ok((1, -2, 3)));
+
+    let out: Result<(u8, i8, u16), _> = (1u32, -2i64, -3i32).try_convert();
+    let err = out.unwrap_err();
+    assert!(matches!(err, TryConvertError3::Elem2(_)));
+    assert_eq!(err.index(), 2);
+    assert!(err.to_string().starts_with("element 2: "));
+}
+
+#[test]
+fn try_map() {
+    struct Parse(usize);
+
+    impl<'a> TryMapper<&'a str> for Parse {
+        type Output = u32;
+        type Error = usize;
+
+        fn try_map(&mut self, elem: &'a str) -> Result<u32, usize> {
+            self.0 += 1;
+            elem.parse().map_err(|_| self.0 - 1)
+        }
+    }
+
+    impl<'a> TryMapper<&'a &'a str> for Parse {
+        type Output = u32;
+        type Error = usize;
+
+        fn try_map(&mut self, elem: &'a &'a str) -> Result<u32, usize> {
+            self.try_map(*elem)
+        }
+    }
+
+    assert_eq!(("1", "2", "3").try_map(Parse(0)), Ok((1, 2, 3)));
+    assert_eq!(("1", "x", "y").try_map(Parse(0)), Err(1));
+    assert_eq!((&("4", "5")).try_map(Parse(0)), Ok((4, 5)));
+    assert_eq!(().try_map(Parse(0)), Ok(()));
+}
+
+#[cfg(feature = "futures")]
+#[test]
+fn map_async() {
+    use futures::executor::block_on;
+    use futures::future::{poll_fn, ready, BoxFuture, FutureExt};
+    use std::task::Poll;
+
+    struct Double;
+
+    impl AsyncMapper<u32> for Double {
+        type Future = BoxFuture<'static, u64>;
+
+        fn map_async(&mut self, elem: u32) -> Self::Future {
+            async move {
+                // Yield once so the other futures get polled in between.
+                let mut yielded = false;
+                poll_fn(|cx| {
+                    if yielded {
+                        Poll::Ready(())
+                    } else {
+                        yielded = true;
+                        cx.waker().wake_by_ref();
+                        Poll::Pending
+                    }
+                })
+                .await;
+                elem as u64 * 2
+            }
+            .boxed()
+        }
+    }
+
+    impl AsyncMapper<&'static str> for Double {
+        type Future = futures::future::Ready<String>;
+
+        fn map_async(&mut self, elem: &'static str) -> Self::Future {
+            ready(elem.repeat(2))
+        }
+    }
+
+    assert_eq!(
+        block_on((1u32, "ab", 3u32).map_async(Double)),
+        (2, "abab".to_string(), 6)
+    );
+    assert_eq!(block_on(().map_async(Double)), ());
+
+    impl<'a> AsyncMapper<&'a u32> for Double {
+        type Future = futures::future::Ready<u32>;
+
+        fn map_async(&mut self, elem: &'a u32) -> Self::Future {
+            ready(*elem + 1)
+        }
+    }
+
+    assert_eq!(block_on((&(1u32, 2u32)).map_async(Double)), (2, 3));
+}
+
+#[test]
+fn apply_each() {
+    let out = (|x: u32| x + 1, str::len, |c: char| c.is_numeric()).apply_each((1, "abc", 'x'));
+    assert_eq!(out, (2, 3, false));
+
+    let fns = (|x: i32| -x,);
+    assert_eq!((&fns).apply_each((1,)), (-1,));
+    assert_eq!((&fns).apply_each((2,)), (-2,));
+
+    assert_eq!(().apply_each(()), ());
+}
+
+#[test]
+fn pipe() {
+    let out = (str::trim, str::len, |n: usize| n as u64 * 2).pipe("  abc ");
+    assert_eq!(out, 6);
+
+    let stages = (|x: i32| x + 1, |x: i32| x.to_string());
+    assert_eq!((&stages).pipe(1), "2");
+    assert_eq!((&stages).pipe(2), "3");
+
+    assert_eq!(().pipe('a'), 'a');
+    assert_eq!((&()).pipe('a'), 'a');
+}
+
+#[test]
+fn call() {
+    assert_eq!((1, 2, 3).apply(|a, b, c| a + b * c), 7);
+    assert_eq!((&(1, 'a')).apply(|a: &i32, b: &char| (*a, *b)), (1, 'a'));
+    assert_eq!(().apply(|| 5), 5);
+
+    let (left, right): ((_,), (_, _)) = ("x", 1, 2).split();
+    assert_eq!(
+        left.join(right).apply(|s: &str, a, b| format!("{s}{a}{b}")),
+        "x12"
+    );
+}
+
+#[test]
+fn curry() {
+    use curry::{curry2, curry4, uncurry2, uncurry4};
+
+    let f = curry4(|a: String, b: u32, c: char, d: bool| format!("{a}{b}{c}{d}"));
+    let g = f("x".to_string())(1);
+    assert_eq!(g('y')(true), "x1ytrue");
+    assert_eq!(g('z')(false), "x1zfalse");
+
+    let f = uncurry4(f);
+    assert_eq!(f("a".to_string(), 2, 'b', true), "a2btrue");
+
+    let sub = uncurry2(|a: i32| move |b: i32| a - b);
+    assert_eq!(sub(5, 3), 2);
+    assert_eq!(curry2(sub)(5)(3), 2);
+}
+
+#[test]
+fn bind_prefix() {
+    let f = super::bind_prefix(
+        |a: u32, b: String, c: char| format!("{a}{b}{c}"),
+        (1, "x".to_string()),
+    );
+    assert_eq!(f(('y',)), "1xy");
+    assert_eq!(f(('z',)), "1xz");
+
+    let all = super::bind_prefix(|a: i32, b: i32| a * b, (6, 7));
+    assert_eq!(all(()), 42);
+
+    let none = super::bind_prefix(|a: i32, b: i32| a * b, ());
+    assert_eq!(none((6, 7)), 42);
+}
+
+#[test]
+fn enumerate() {
+    assert_eq!((1, 'a', "b").enumerate(), ((0, 1), (1, 'a'), (2, "b")));
+    assert_eq!((&(1, 'a')).enumerate(), ((0, &1), (1, &'a')));
+    assert_eq!(().enumerate(), ());
+
+    struct Indexed;
+
+    impl<T: std::fmt::Display> TryMapper<(usize, T)> for Indexed {
+        type Output = String;
+        type Error = ();
+
+        fn try_map(&mut self, (idx, elem): (usize, T)) -> Result<String, ()> {
+            Ok(format!("{idx}={elem}"))
+        }
+    }
+
+    assert_eq!(
+        ('x', 2).enumerate().try_map(Indexed),
+        Ok(("0=x".to_string(), "1=2".to_string()))
+    );
+}
+
+#[test]
+fn zip_with() {
+    struct Concat;
+
+    impl<L: std::fmt::Display, R: std::fmt::Display> BiMapper<L, R> for Concat {
+        type Output = String;
+
+        fn combine(&mut self, left: L, right: R) -> String {
+            format!("{left}{right}")
+        }
+    }
+
+    assert_eq!(
+        (1, 'a').zip_with(("x", 2.5), Concat),
+        ("1x".to_string(), "a2.5".to_string())
+    );
+    assert_eq!((&(1,)).zip_with(&('b',), Concat), ("1b".to_string(),));
+    assert_eq!(().zip_with((), Concat), ());
+}
+
+#[test]
+fn fold_with() {
+    struct Diffs;
+
+    impl<T: PartialEq> BiFolder<Vec<usize>, (usize, T), (usize, T)> for Diffs {
+        fn fold(
+            &mut self,
+            mut acc: Vec<usize>,
+            (idx, l): (usize, T),
+            (_, r): (usize, T),
+        ) -> Vec<usize> {
+            if l != r {
+                acc.push(idx);
+            }
+            acc
+        }
+    }
+
+    let left = (1, 'a', "x");
+    let right = (1, 'b', "y");
+    assert_eq!(
+        left.enumerate().fold_with(right.enumerate(), vec![], Diffs),
+        vec![1, 2]
+    );
+
+    struct Count;
+
+    impl<L, R> BiFolder<usize, L, R> for Count {
+        fn fold(&mut self, acc: usize, _: L, _: R) -> usize {
+            acc + 1
+        }
+    }
+
+    assert_eq!((&(1, 2)).fold_with(&('a', 'b'), 0, Count), 2);
+    assert_eq!(().fold_with((), 0, Count), 0);
+}
+
+#[test]
+fn homogeneous_array() {
+    assert_eq!((1, 2, 3).into_array(), [1, 2, 3]);
+    assert_eq!(<(_, _)>::from_array(["a", "b"]), ("a", "b"));
+    assert_eq!(HomogeneousTuple::<u8>::into_array(()), [0u8; 0]);
+    let () = HomogeneousTuple::<u8>::from_array([]);
+
+    let max = seq!(J in 0..16 { (#(J,)*) }).into_array();
+    assert_eq!(max, seq!(J in 0..16 { [#(J,)*] }));
+    let seq!(J in 0..16 { (#(_~J,)*) }) = HomogeneousTuple::from_array(max);
+}
+
+#[test]
+fn homogeneous_iter() {
+    let mut t = (1, 2, 3, 4);
+    assert_eq!(t.iter().rev().copied().collect::<Vec<_>>(), [4, 3, 2, 1]);
+    assert_eq!(t.iter().len(), 4);
+
+    for v in t.iter_mut() {
+        *v += 1;
+    }
+    assert_eq!(t, (2, 3, 4, 5));
+    assert_eq!(t.into_iter().collect::<Vec<_>>(), [2, 3, 4, 5]);
+
+    assert_eq!(HomogeneousTuple::<u8>::iter(&()).count(), 0);
+}
+
+#[test]
+fn homogeneous_try_from() {
+    let row: (u8, u8) = HomogeneousTuple::try_from_slice(&[1, 2]).unwrap();
+    assert_eq!(row, (1, 2));
+
+    let err = <(u8, u8)>::try_from_slice(&[1, 2, 3]).unwrap_err();
+    assert_eq!((err.expected(), err.found()), (2, 3));
+
+    let row: (String,) = HomogeneousTuple::try_from_vec(vec!["a".to_string()]).unwrap();
+    assert_eq!(row, ("a".to_string(),));
+
+    let err = <(String, String)>::try_from_vec(vec![]).unwrap_err();
+    assert_eq!(err.to_string(), "expected 2 elements, found 0");
+
+    assert_eq!(HomogeneousTuple::<u8>::try_from_vec(vec![]), Ok(()));
+}
+
+#[test]
+fn homogeneous_map() {
+    let mut calls = vec![];
+    let out = (3, 1, 2).map(|v| {
+        calls.push(v);
+        v.to_string()
+    });
+    assert_eq!(out, ("3".to_string(), "1".to_string(), "2".to_string()));
+    assert_eq!(calls, [3, 1, 2]);
+
+    let () = HomogeneousTuple::<u8>::map((), |v| v + 1);
+}
+
+#[test]
+fn homogeneous_fold() {
+    assert_eq!((1, 2, 3, 4).fold(0, |acc, v| acc * 10 + v), 1234);
+    assert_eq!(("a",).reduce(|_, _| unreachable!()), Some("a"));
+    assert_eq!((1, 2, 3).reduce(|a, b| a - b), Some(-4));
+    assert_eq!(
+        HomogeneousTuple::<u8>::fold((), 5, |_, _| unreachable!()),
+        5
+    );
+}
+
+#[test]
+fn homogeneous_sum_product() {
+    assert_eq!((1u32, 2, 3).sum(), 6);
+    assert_eq!((1u32, 2, 3).product(), 6);
+    assert_eq!(HomogeneousTuple::<i64>::sum(()), 0);
+    assert_eq!(HomogeneousTuple::<i64>::product(()), 1);
+}
+
+#[test]
+fn homogeneous_min_max() {
+    let t = (4, 1, 9, 1, 9);
+    assert_eq!(t.min_elem(), Some((1, 1)));
+    assert_eq!(t.max_elem(), Some((4, 9)));
+    assert_eq!(t.min_elem_by_key(|v| (*v - 5i32).abs()), Some((0, 4)));
+    assert_eq!(t.max_elem_by_key(|v| (*v - 5i32).abs()), Some((4, 9)));
+    assert_eq!(HomogeneousTuple::<u8>::min_elem(()), None);
+    assert_eq!(HomogeneousTuple::<u8>::max_elem_by_key((), |v| *v), None);
+}
+
+#[test]
+fn homogeneous_predicates() {
+    let t = ('a', 'b', 'c');
+    assert!(t.contains(&'c'));
+    assert!(!t.contains(&'d'));
+    assert_eq!(t.position(|c| *c > 'a'), Some(1));
+    assert_eq!(t.position(|c| *c > 'c'), None);
+    assert!(t.all(char::is_ascii_lowercase));
+    assert!(!t.any(char::is_ascii_digit));
+
+    assert!(HomogeneousTuple::<u8>::all(&(), |_| false));
+    assert!(!HomogeneousTuple::<u8>::any(&(), |_| true));
+}
+
+#[test]
+fn homogeneous_repeat() {
+    let t: TupleRepeat<String, 2> = super::repeat::<2, _>("a".to_string());
+    assert_eq!(t, ("a".to_string(), "a".to_string()));
+
+    let () = super::repeat::<0, u8>(1);
+    let seq!(J in 0..16 { (#(_~J,)*) }) = super::repeat::<16, _>(1u8);
+
+    let t: (_, _) = HomogeneousTuple::repeat(1.5);
+    assert_eq!(t, (1.5, 1.5));
+}
+
+#[test]
+fn homogeneous_from_fn() {
+    let mut calls = vec![];
+    let t = super::from_fn::<3, _>(|i| {
+        calls.push(i);
+        i * i
+    });
+    assert_eq!(t, (0, 1, 4));
+    assert_eq!(calls, [0, 1, 2]);
+
+    let () = super::from_fn::<0, u8>(|_| unreachable!());
+}
+
+#[test]
+fn homogeneous_sort() {
+    // Check every network against all 0-1 inputs, which is sufficient for
+    // any input.
+    fn check<T: TupleSort<u8>>(len: u32) {
+        for bits in 0..1u32 << len {
+            let mut t = T::from_fn(|i| (bits >> i) as u8 & 1);
+            t.sort();
+            let sorted = t.into_iter().collect::<Vec<_>>();
+            assert!(
+                sorted.windows(2).all(|w| w[0] <= w[1]),
+                "len {len} bits {bits:b}"
+            );
+            assert_eq!(
+                sorted.iter().filter(|v| **v == 1).count(),
+                bits.count_ones() as usize
+            );
+        }
+    }
+
+    seq!(N in 0..=16 {
+        check::<TupleRepeat<u8, N>>(N);
+    });
+
+    let mut t = ("b", "a", "c");
+    t.sort_by_key(|s| std::cmp::Reverse(*s));
+    assert_eq!(t, ("c", "b", "a"));
+}
+
+#[test]
+fn zip_with_closure() {
+    let mut calls = 0;
+    let out = (1, 2, 3).zip_with((10, 20, 30), |a: i32, b: i32| {
+        calls += 1;
+        a * b
+    });
+    assert_eq!(out, (10, 40, 90));
+    assert_eq!(calls, 3);
+
+    assert_eq!(
+        ("a", "b").zip_with((1, 2), |s: &str, n: usize| s.repeat(n)),
+        ("a".to_string(), "bb".to_string())
+    );
+    assert_eq!(
+        (&(1, 2)).zip_with(&(3, 4), |a: &i32, b: &i32| a + b),
+        (4, 6)
+    );
+}
+
+#[test]
+fn homogeneous_scan() {
+    assert_eq!((1, 2, 3).scan(10, |acc, v| acc * v), (10, 20, 60));
+    assert_eq!(
+        ("a", "b", "c").scan(String::new(), |acc, v| format!("{acc}{v}")),
+        ("a".to_string(), "ab".to_string(), "abc".to_string())
+    );
+    let () = HomogeneousTuple::<u8>::scan((), 0, |_, _| unreachable!());
+}
+
+#[test]
+fn homogeneous_filter() {
+    let t = ("apple", "kiwi", "avocado");
+    assert_eq!(t.filter(|s| s.starts_with('a')), ["apple", "avocado"]);
+    assert_eq!(
+        t.partition(|s| s.len() > 4),
+        (vec!["apple", "avocado"], vec!["kiwi"])
+    );
+    assert!(HomogeneousTuple::<u8>::filter((), |_| true).is_empty());
+}
+
+#[test]
+fn soa() {
+    let aos = [(1, "a".to_string(), 'x'), (2, "b".to_string(), 'y')];
+    let soa = aos.clone().into_soa();
+    assert_eq!(
+        soa,
+        ([1, 2], ["a".to_string(), "b".to_string()], ['x', 'y'])
+    );
+    assert_eq!(soa.into_aos(), aos);
+
+    let empty: [(u8, char); 0] = [];
+    assert_eq!(empty.into_soa(), ([], []));
+    assert_eq!(([1], ['a']).into_aos(), [(1, 'a')]);
+}
+
+#[test]
+fn arith() {
+    use std::time::Duration;
+
+    let a = (3u8, 2.5f32, Duration::from_secs(2));
+    let b = (2u8, 0.5f32, Duration::from_secs(1));
+    assert_eq!(a.add_each(b), (5, 3.0, Duration::from_secs(3)));
+    assert_eq!(a.sub_each(b), (1, 2.0, Duration::from_secs(1)));
+    assert_eq!((&(3, 2.0)).mul_each(&(2, 0.5)), (6, 1.0));
+    assert_eq!(
+        (Duration::from_secs(4), 9).div_each((2, 2)),
+        (Duration::from_secs(2), 4)
+    );
+    assert_eq!(("a".to_string(),).add_each(("b",)), ("ab".to_string(),));
+    assert_eq!(().add_each(()), ());
+}
+
+#[test]
+fn int_arith() {
+    let a = (200u8, -100i16, 5usize);
+    assert_eq!(a.checked_add_each((55, -100, 1)), Some((255, -200, 6)));
+    assert_eq!(a.checked_add_each((56, 0, 0)), None);
+    assert_eq!(a.checked_div_each((1, 1, 0)), None);
+    assert_eq!(a.saturating_sub_each((201, i16::MAX, 6)), (0, i16::MIN, 0));
+    assert_eq!(a.wrapping_mul_each((2, 1, 2)), (144, -100, 10));
+    assert_eq!(().checked_mul_each(()), Some(()));
+}
+
+#[test]
+fn homogeneous_dot_length() {
+    assert_eq!((1, -2, 3).dot((4, 5, -6)), -24);
+    assert_eq!(HomogeneousTuple::<i32>::dot((), ()), 0);
+    assert_eq!((1u32, 2, 2).length_squared(), 9);
+    assert_eq!((0.6f64, 0.8).length(), 1.0);
+    assert_eq!(HomogeneousTuple::<f32>::length(()), 0.0);
+}
+
+#[test]
+fn min_max_each() {
+    let a = (1, 2.5, "b");
+    let b = (0, 3.5, "a");
+    assert_eq!(a.min_each(b), (0, 2.5, "a"));
+    assert_eq!(a.max_each(b), (1, 3.5, "b"));
+    assert_eq!(
+        (5, -1.0, "c").clamp_each((0, 0.0, "a"), (3, 1.0, "b")),
+        (3, 0.0, "b")
+    );
+    // lo wins over an inverted hi
+    assert_eq!((5,).clamp_each((4,), (2,)), (4,));
+    let (x, y) = (f64::NAN, 1.0).min_each((0.0, f64::NAN));
+    assert!(x.is_nan() && y == 1.0);
+    assert_eq!(().clamp_each((), ()), ());
+}
+
+#[test]
+fn homogeneous_lerp() {
+    let a = (0.0f32, 1.0, -4.0);
+    let b = (2.0f32, 1.0, 4.0);
+    assert_eq!(a.lerp(b, 0.0), a);
+    assert_eq!(a.lerp(b, 1.0), b);
+    assert_eq!(a.lerp(b, 0.5), (1.0, 1.0, 0.0));
+    assert_eq!(a.lerp(b, 2.0), (4.0, 1.0, 12.0));
+    assert_eq!((1.0f64,).lerp((3.0,), 0.75), (2.5,));
+}
+
+#[test]
+fn homogeneous_stats() {
+    assert_eq!((1.5f32, 2.5).mean(), Some(2.0));
+    assert_eq!((-3i32, 3).mean(), Some(0.0));
+    assert_eq!((7u16,).variance(), Some(0.0));
+    assert_eq!((1.0, 3.0).variance(), Some(1.0));
+    assert_eq!((1i8, 3, 1, 3).stddev(), Some(1.0));
+    assert_eq!(HomogeneousTuple::<f64>::mean(()), None);
+    assert_eq!(HomogeneousTuple::<u8>::variance(()), None);
+    assert_eq!(HomogeneousTuple::<u8>::stddev(()), None);
+}
+
+#[cfg(feature = "portable_simd")]
+#[test]
+fn simd() {
+    use std::simd::Simd;
+
+    let a = (1u32, 2, 3).into_simd();
+    let b = (10u32, 20, 30).into_simd();
+    assert_eq!(<(u32, u32, u32)>::from_simd(a + b), (11, 22, 33));
+    assert_eq!((1.0f32,).into_simd(), Simd::from_array([1.0]));
+}
+
+#[cfg(feature = "bytemuck")]
+#[test]
+fn pod() {
+    use pod::PodTuple;
+
+    let mut points = [PodTuple::from((1u16, 2)), PodTuple::from((3, 4))];
+    assert_eq!(bytemuck::cast_slice::<_, u16>(&points), &[1, 2, 3, 4]);
+    bytemuck::cast_slice_mut::<_, u16>(&mut points)[3] = 5;
+    assert_eq!(<(u16, u16)>::from(points[1]), (3, 5));
+    assert_eq!(<[u16; 2]>::from(points[0]), [1, 2]);
+    assert!(PodTuple::<u32, 0>::from(()).as_bytes().is_empty());
+    assert_eq!(<()>::from(PodTuple::<u8, 0>([])), ());
+}
+
+#[test]
+fn transpose() {
+    assert_eq!(
+        (Some(1), Some("x"), Some(2.5)).transpose(),
+        Some((1, "x", 2.5))
+    );
+    assert_eq!((Some(1), None::<&str>, Some(2.5)).transpose(), None);
+    assert_eq!(().transpose(), Some(()));
+
+    let t = (Some(String::from("a")), Some(1));
+    assert_eq!((&t).transpose(), Some((&String::from("a"), &1)));
+    assert_eq!((&(None::<u8>,)).transpose(), None);
+}
+
+#[test]
+fn try_collect() {
+    let ok: (Result<_, &str>, Result<_, &str>) = (Ok(1), Ok('a'));
+    assert_eq!(ok.try_collect(), Ok((1, 'a')));
+    assert_eq!((&ok).try_collect(), Ok((&1, &'a')));
+
+    let err: (Result<u8, _>, Result<u8, _>, Result<u8, _>) = (Ok(1), Err("first"), Err("second"));
+    assert_eq!(err.try_collect(), Err("first"));
+    assert_eq!((&err).try_collect(), Err(&"first"));
+
+    assert_eq!(TupleTryCollect::<()>::try_collect(()), Ok(()));
+}
+
+#[test]
+fn validate() {
+    let ok: (Result<_, &str>, Result<_, &str>) = (Ok(1), Ok('a'));
+    assert_eq!(ok.validate(), Ok((1, 'a')));
+    assert_eq!((&ok).validate(), Ok((&1, &'a')));
+
+    let err: (Result<u8, _>, Result<u8, _>, Result<u8, _>) = (Err("first"), Ok(2), Err("third"));
+    assert_eq!(err.validate(), Err(vec!["first", "third"]));
+    assert_eq!((&err).validate(), Err(vec![&"first", &"third"]));
+
+    assert_eq!(TupleValidate::<()>::validate(()), Ok(()));
+}
+
+#[test]
+fn unzip() {
+    assert_eq!(
+        unzip_option(Some((1, "a", 2.5))),
+        (Some(1), Some("a"), Some(2.5))
+    );
+    assert_eq!(unzip_option(None::<(u8, &str, f32)>), (None, None, None));
+    assert_eq!(unzip_option(Some(())), ());
+
+    let ok: Result<_, String> = Ok((1, 'x'));
+    assert_eq!(unzip_result(ok), (Ok(1), Ok('x')));
+    let err: Result<(u8, char, ()), _> = Err(String::from("oops"));
+    let (a, b, c) = unzip_result(err);
+    assert_eq!(
+        (a.unwrap_err(), b.unwrap_err(), c.unwrap_err()),
+        ("oops".into(), "oops".into(), "oops".into())
+    );
+
+    // Round trip with transpose
+    assert_eq!(unzip_option(Some((1, 2))).transpose(), Some((1, 2)));
+}
+
+#[test]
+fn options_helpers() {
+    let mut t = (Some(1), Some(String::from("a")), None::<f32>);
+    let taken = t.take_all();
+    assert_eq!(taken, (Some(1), Some(String::from("a")), None));
+    assert_eq!(t, (None, None, None));
+    assert_eq!(
+        t.take_all().unwrap_or_default_each(),
+        (0, String::new(), 0.0)
+    );
+    assert_eq!(taken.unwrap_or_default_each(), (1, String::from("a"), 0.0));
+    assert_eq!(().take_all(), ());
+}
+
+#[test]
+fn zip_iters() {
+    let mut it = (0..3, vec!["a", "b", "c", "d"], [1.0, 2.0, 3.0].iter()).zip_iters();
+    assert_eq!(it.size_hint(), (3, Some(3)));
+    assert_eq!(it.next(), Some((0, "a", &1.0)));
+    assert_eq!(it.collect::<Vec<_>>(), [(1, "b", &2.0), (2, "c", &3.0)]);
+
+    let it = (0.., "xy".chars()).zip_iters();
+    assert_eq!(it.size_hint(), (1, Some(2)));
+    assert_eq!(it.count(), 2);
+
+    assert_eq!((0..5,).zip_iters().last(), Some((4,)));
+}
+
+#[test]
+fn unzip_n() {
+    let (a, b, c): (Vec<_>, Vec<_>, String) = (0..3).map(|i| (i, i * 2, 'a')).unzip_n();
+    assert_eq!(
+        (a, b, c),
+        (vec![0, 1, 2], vec![0, 2, 4], String::from("aaa"))
+    );
+
+    let (a,): (Vec<u8>,) = std::iter::empty::<(u8,)>().unzip_n();
+    assert!(a.is_empty());
+
+    // Round trip with zip_iters
+    let (x, y): (Vec<_>, Vec<_>) = (0..4, "abcd".chars()).zip_iters().unzip_n();
+    assert_eq!((x, y), (vec![0, 1, 2, 3], vec!['a', 'b', 'c', 'd']));
+}
+
+#[test]
+fn cartesian() {
+    let all: Vec<_> = (0..3, "xy".chars()).cartesian().collect();
+    assert_eq!(
+        all,
+        [(0, 'x'), (0, 'y'), (1, 'x'), (1, 'y'), (2, 'x'), (2, 'y')]
+    );
+
+    assert_eq!((0..2, 0..3, 0..4, 0..5).cartesian().count(), 120);
+    assert_eq!((0..2, 0..0, 0..4).cartesian().count(), 0);
+    assert_eq!(
+        (vec![String::from("a")],).cartesian().collect::<Vec<_>>(),
+        [(String::from("a"),)]
+    );
+
+    // Empty product has exactly one (empty) combination
+    let mut it = ().cartesian();
+    assert_eq!(it.next(), Some(()));
+    assert_eq!(it.next(), None);
+    assert_eq!(it.next(), None);
+}
+
+#[cfg(feature = "futures")]
+#[test]
+fn join_all_tuple() {
+    use futures::executor::block_on;
+    use futures::future::{pending, ready, FutureExt};
+
+    let fut = (ready(1), async { "a" }, ready(2.5)).join_all_tuple();
+    assert_eq!(block_on(fut), (1, "a", 2.5));
+    assert_eq!(block_on(().join_all_tuple()), ());
+
+    // Not ready until every future is
+    let mut fut = (ready(1), pending::<()>()).join_all_tuple();
+    assert!((&mut fut).now_or_never().is_none());
+}
+
+#[cfg(feature = "futures")]
+#[test]
+fn try_join_tuple() {
+    use futures::executor::block_on;
+    use futures::future::{pending, ready, FutureExt};
+
+    let fut = (ready(Ok::<_, &str>(1)), async { Ok("a") }).try_join_tuple();
+    assert_eq!(block_on(fut), Ok((1, "a")));
+
+    // Short-circuits on an error even though the first future never completes
+    let fut = (
+        pending::<Result<u8, _>>(),
+        ready(Ok(2)),
+        ready(Err::<(), _>("bad")),
+    );
+    assert_eq!(block_on(fut.try_join_tuple()), Err("bad"));
+
+    let mut fut = (ready(Ok::<_, ()>(1)), pending::<Result<(), ()>>()).try_join_tuple();
+    assert!((&mut fut).now_or_never().is_none());
+
+    assert_eq!(block_on(TupleTryJoin::<()>::try_join_tuple(())), Ok(()));
+}
+
+#[cfg(feature = "futures")]
+#[test]
+fn race() {
+    use future::{Either1, Either2, Either3};
+    use futures::executor::block_on;
+    use futures::future::{pending, ready};
+
+    assert_eq!(block_on((ready(1),).race()), Either1::E0(1));
+    assert_eq!(block_on((ready(1), ready("a")).race()), Either2::E0(1));
+    assert_eq!(
+        block_on((pending::<u8>(), pending::<()>(), async { 'c' }).race()),
+        Either3::E2('c')
+    );
+}
+
+#[cfg(feature = "futures")]
+#[test]
+fn zip_streams() {
+    use futures::executor::block_on;
+    use futures::stream::{self, StreamExt};
+
+    let zipped = (
+        stream::iter(0..3),
+        stream::iter(["a", "b", "c", "d"]),
+        stream::repeat(1.5),
+    )
+        .zip_streams();
+    assert_eq!(
+        block_on(zipped.collect::<Vec<_>>()),
+        [(0, "a", 1.5), (1, "b", 1.5), (2, "c", 1.5)]
+    );
+
+    // A stream which is pending between items
+    let slow = stream::iter(0..2).then(|x| async move {
+        let mut yielded = false;
+        futures::future::poll_fn(|cx| {
+            if yielded {
+                std::task::Poll::Ready(x)
+            } else {
+                yielded = true;
+                cx.waker().wake_by_ref();
+                std::task::Poll::Pending
+            }
+        })
+        .await
+    });
+    let zipped = (slow, stream::iter("xyz".chars())).zip_streams();
+    assert_eq!(block_on(zipped.collect::<Vec<_>>()), [(0, 'x'), (1, 'y')]);
+
+    // Streams aren't polled again once one has ended
+    let mut polls = 0;
+    let once = stream::poll_fn(|_| {
+        polls += 1;
+        assert!(polls <= 2, "polled after end");
+        std::task::Poll::Ready((polls == 1).then_some(0))
+    });
+    let mut zipped = (stream::iter("abc".chars()), once).zip_streams();
+    block_on(async {
+        use futures::stream::FusedStream;
+
+        assert_eq!(zipped.next().await, Some(('a', 0)));
+        assert!(!zipped.is_terminated());
+        assert_eq!(zipped.next().await, None);
+        assert!(zipped.is_terminated());
+        assert_eq!(zipped.next().await, None);
+    });
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn par_join() {
+    use std::sync::Barrier;
+
+    // All three must be running at once to pass the barrier
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(3)
+        .build()
+        .unwrap();
+    let barrier = Barrier::new(3);
+    let out = pool.install(|| {
+        super::par_join((
+            || {
+                barrier.wait();
+                1
+            },
+            || {
+                barrier.wait();
+                "two"
+            },
+            || {
+                barrier.wait();
+                3.0
+            },
+        ))
+    });
+    assert_eq!(out, (1, "two", 3.0));
+    assert_eq!(super::par_join(()), ());
+}
+
+#[test]
+fn scope_join() {
+    use std::sync::Barrier;
+
+    // All three must be running at once to pass the barrier
+    let barrier = Barrier::new(3);
+    let mut counter = 0;
+    let out = super::scope_join((
+        || {
+            barrier.wait();
+            1
+        },
+        || {
+            barrier.wait();
+            counter += 1;
+            "two"
+        },
+        || {
+            barrier.wait();
+            3.0
+        },
+    ));
+    assert_eq!(out, (1, "two", 3.0));
+    assert_eq!(counter, 1);
+    assert_eq!(super::scope_join(()), ());
+
+    let res = std::panic::catch_unwind(|| super::scope_join((|| 1, || panic!("boom"))));
+    assert_eq!(*res.unwrap_err().downcast::<&str>().unwrap(), "boom");
+}
+
+#[cfg(feature = "tokio")]
+#[test]
+fn spawn_all() {
+    let rt = tokio::runtime::Builder::new_current_thread()
+        .build()
+        .unwrap();
+    rt.block_on(async {
+        let handles = (async { 1 }, async { String::from("a") }, async {}).spawn_all();
+        assert_eq!(
+            handles.join_handles().await.unwrap(),
+            (1, String::from("a"), ())
+        );
+
+        let handles = (async { 1 }, async { panic!("task failed") }).spawn_all();
+        let err: Result<(u8, ()), _> = handles.join_handles().await;
+        assert!(err.unwrap_err().is_panic());
+
+        let () = ().spawn_all();
+        assert_eq!(().join_handles().await.unwrap(), ());
+    });
+}
+
+#[test]
+fn lock_all() {
+    use std::sync::{Arc, Mutex, TryLockError};
+
+    let a = Arc::new((Mutex::new(0), Mutex::new(String::new())));
+
+    {
+        let (mut n, s) = a.lock_all().unwrap();
+        *n += 1;
+        assert!(s.is_empty());
+        assert!(matches!(a.try_lock_all(), Err(TryLockError::WouldBlock)));
+    }
+    // Failed try_lock_all doesn't leave anything locked
+    assert!(a.0.try_lock().is_ok() && a.1.try_lock().is_ok());
+
+    // Poisoning still returns the guards
+    let a2 = a.clone();
+    let _ = std::thread::spawn(move || {
+        let _guard = a2.1.lock().unwrap();
+        panic!("poison");
+    })
+    .join();
+    let err = a.lock_all().unwrap_err();
+    assert_eq!(*err.into_inner().0, 1);
+    assert!(matches!(a.try_lock_all(), Err(TryLockError::Poisoned(_))));
+
+    let () = ().lock_all().unwrap();
+
+    // Tuples of references with the same mutexes in different orders don't
+    // deadlock against each other.
+    let (x, y) = (Mutex::new(0), Mutex::new(0));
+    std::thread::scope(|s| {
+        s.spawn(|| {
+            for _ in 0..10000 {
+                let (mut x, mut y) = (&x, &y).lock_all().unwrap();
+                *x += 1;
+                *y += 1;
+            }
+        });
+        s.spawn(|| {
+            for _ in 0..10000 {
+                let (mut y, mut x) = (&y, &x).lock_all().unwrap();
+                *x += 1;
+                *y += 1;
+            }
+        });
+    });
+    assert_eq!(
+        (x.into_inner().unwrap(), y.into_inner().unwrap()),
+        (20000, 20000)
+    );
+}
+
+#[test]
+#[should_panic(expected = "same mutex appears twice in tuple")]
+fn lock_all_duplicate() {
+    use std::sync::Mutex;
+
+    let m = Mutex::new(1);
+    let _ = (&m, &Mutex::new(2), &m).lock_all();
+}
+
+#[test]
+#[should_panic(expected = "same mutex appears twice in tuple")]
+fn try_lock_all_duplicate() {
+    use std::sync::Mutex;
+
+    let m = Mutex::new(1);
+    let _ = (&m, &m).try_lock_all();
+}
+
+#[test]
+fn rwlock_all() {
+    use std::sync::RwLock;
+
+    let locks = (RwLock::new(1), RwLock::new(vec!['a']));
+    {
+        let (a, b) = locks.read_all().unwrap();
+        let (c, d) = locks.read_all().unwrap();
+        assert_eq!((*a, b.len(), *c, d.len()), (1, 1, 1, 1));
+        assert!(locks.0.try_write().is_err());
+    }
+    {
+        let (mut a, mut b) = locks.write_all().unwrap();
+        *a += 1;
+        b.push('b');
+        assert!(locks.1.try_read().is_err());
+    }
+    assert_eq!(*locks.0.read().unwrap(), 2);
+
+    // Opposite tuple orders by reference
+    let (x, y) = (RwLock::new(0), RwLock::new(0));
+    std::thread::scope(|s| {
+        s.spawn(|| {
+            for _ in 0..10000 {
+                let (mut x, _y) = (&x, &y).write_all().unwrap();
+                *x += 1;
+            }
+        });
+        s.spawn(|| {
+            for _ in 0..10000 {
+                let (mut y, _x) = (&y, &x).write_all().unwrap();
+                *y += 1;
+            }
+        });
+    });
+    assert_eq!(
+        (x.into_inner().unwrap(), y.into_inner().unwrap()),
+        (10000, 10000)
+    );
+    let ((), ()) = (().read_all().unwrap(), ().write_all().unwrap());
+}
+
+#[test]
+#[should_panic(expected = "same rwlock appears twice in tuple")]
+fn write_all_duplicate() {
+    use std::sync::RwLock;
+
+    let l = RwLock::new(1);
+    let _ = (&l, &l).write_all();
+}
+
+#[test]
+#[should_panic(expected = "same rwlock appears twice in tuple")]
+fn read_all_duplicate() {
+    use std::sync::RwLock;
+
+    let l = RwLock::new(1);
+    let _ = (&RwLock::new(0), &l, &l).read_all();
+}
+
+#[test]
+fn borrow_all() {
+    use std::cell::RefCell;
+
+    let cells = (RefCell::new(1), RefCell::new(String::from("a")));
+    {
+        let (a, b) = cells.borrow_all();
+        let (c, _d) = cells.borrow_all();
+        assert_eq!((*a, b.as_str(), *c), (1, "a", 1));
+        assert!(cells.0.try_borrow_mut().is_err());
+    }
+    {
+        let (mut b, mut a) = (&cells.1, &cells.0).borrow_mut_all();
+        *a += 1;
+        b.push('b');
+    }
+    assert_eq!(cells, (RefCell::new(2), RefCell::new(String::from("ab"))));
+
+    let _held = cells.1.borrow();
+    let res = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        cells.borrow_mut_all();
+    }));
+    assert!(res.is_err());
+    // The first element's borrow was released when unwinding
+    assert!(cells.0.try_borrow_mut().is_ok());
+
+    let () = ().borrow_all();
+}
+
+#[test]
+fn thread_join_all() {
+    use std::thread;
+
+    let (a, b) = (thread::spawn(|| 1), thread::spawn(|| String::from("b"))).join_all();
+    assert_eq!((a.unwrap(), b.unwrap()), (1, String::from("b")));
+
+    let mut n = 0;
+    let (x, y) = thread::scope(|s| {
+        (
+            s.spawn(|| {
+                n += 1;
+                n
+            }),
+            s.spawn(|| panic!("scoped")),
+        )
+            .join_all()
+    });
+    assert_eq!(x.unwrap(), 1);
+    assert_eq!(*y.unwrap_err().downcast::<&str>().unwrap(), "scoped");
+
+    ().join_all();
+}
+
+#[test]
+fn len_idx() {
+    let t = (1, "a", 2.5);
+    assert_eq!(t.len(), 3);
+    assert!(!t.is_empty() && ().is_empty());
+    assert_eq!(<(u8, u8) as TupleLen>::LEN, 2);
+    assert_eq!(TupleIdx::<0>::idx(&t), &1);
+    assert_eq!(TupleIdx::<2>::idx(t), 2.5);
+}
+
+#[test]
+fn get() {
+    let t = (1u8, 'a', String::from("bc"));
+    assert!(t.get::<1>().is_alphabetic());
+    assert_eq!(t.get::<2>().len(), 2);
+    assert_eq!(*t.get::<0>() + 1, 2);
+    assert_eq!(t.idx_of::<2>() + "d", "bcd");
+
+    let wide = (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, "last");
+    assert_eq!(wide.get::<15>(), &"last");
+}
+
+#[test]
+fn get_mut() {
+    let mut t = (1u8, 'a', String::from("bc"));
+    *t.get_mut::<0>() += 1;
+    *t.get_mut::<1>() = 'z';
+    t.get_mut::<2>().push('d');
+    assert_eq!(t, (2, 'z', String::from("bcd")));
+    TupleIdx::<0>::idx(&mut t).clone_from(&7);
+    assert_eq!(t.0, 7);
+
+    let mut wide = (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15);
+    *wide.get_mut::<15>() *= 2;
+    assert_eq!(wide.15, 30);
+}
+
+#[test]
+fn tup() {
+    let mut t = Tup::from((1, 'a')).join((String::from("b"),));
+    assert_eq!((t.len(), t.is_empty()), (3, false));
+    assert_eq!(t.idx::<2>(), "b");
+    // `t.0` is the inner tuple, so go via `DerefMut`
+    (*t).0 += 1;
+    assert_eq!(*t.idx::<0>(), 2);
+
+    let (l, r): (Tup<(i32, char)>, Tup<(String,)>) = t.clone().split();
+    assert_eq!(
+        (l.into_inner(), r.into_inner()),
+        ((2, 'a'), (String::from("b"),))
+    );
+    assert_eq!(Tup(()).len(), 0);
+    assert!(Tup((1,)) < Tup((2,)));
+}
+
+#[test]
+fn tup_ops() {
+    let t = Tup((1, "a")) + Tup(()) + Tup((2.5,));
+    assert_eq!(t, Tup((1, "a", 2.5)));
+    assert_eq!(t >> |n: i32, s: &str, f: f64| format!("{n}{s}{f}"), "1a2.5");
+    assert_eq!(Tup(()) >> || 42, 42);
+}
+
+#[test]
+fn debug() {
+    assert_eq!(format!("{:?}", ().debug()), format!("{:?}", ()));
+    assert_eq!(format!("{:?}", (1,).debug()), format!("{:?}", (1,)));
+    assert_eq!(format!("{:?}", (1, "a").debug()), format!("{:?}", (1, "a")));
+    assert_eq!(
+        format!("{:#?}", (1, "a").debug()),
+        format!("{:#?}", (1, "a"))
+    );
+
+    let wide = (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15);
+    assert_eq!(
+        format!("{:?}", wide.debug()),
+        "(0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15)"
+    );
+}
+
+#[test]
+fn debug_indexed() {
+    assert_eq!(format!("{:?}", ().debug_indexed()), "()");
+    assert_eq!(format!("{:?}", (1,).debug_indexed()), "(\n    0: 1,\n)");
+
+    let nested = ("x", (1, Some(2)));
+    assert_eq!(
+        format!("{:?}", nested.debug_indexed()),
+        "(\n    0: \"x\",\n    1: (\n        1,\n        Some(\n            2,\n        ),\n    ),\n)"
+    );
+
+    let wide = (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15);
+    let out = format!("{:?}", wide.debug_indexed());
+    assert_eq!(out.lines().count(), 18);
+    assert!(out.contains("\n    15: 15,\n"));
+}
+
+#[test]
+fn default_tuple() {
+    let () = super::default_tuple();
+    let t: (u8, String, Option<char>) = super::default_tuple();
+    assert_eq!(t, Default::default());
+
+    type Wide = (
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        Vec<u8>,
+    );
+    let wide: Wide = super::default_tuple();
+    assert_eq!((wide.0, wide.14, wide.15), (0, 0, vec![]));
+}
+
+#[test]
+fn ordered() {
+    use std::cmp::Ordering;
+    use wide::Ordered;
+
+    let pairs = [
+        ((1, "b"), (1, "a")),
+        ((0, "z"), (1, "a")),
+        ((1, "a"), (1, "a")),
+    ];
+    for (a, b) in pairs {
+        assert_eq!(a.cmp_tuple(&b), a.cmp(&b));
+        assert_eq!(a.partial_cmp_tuple(&b), a.partial_cmp(&b));
+        assert_eq!(a.eq_tuple(&b), a == b);
+        assert_eq!(Ordered(a).cmp(&Ordered(b)), a.cmp(&b));
+    }
+    assert_eq!((1.0, f64::NAN).partial_cmp_tuple(&(1.0, 0.0)), None);
+    assert_eq!(
+        (0.0, f64::NAN).partial_cmp_tuple(&(1.0, 0.0)),
+        Some(Ordering::Less)
+    );
+    assert_eq!(().cmp_tuple(&()), Ordering::Equal);
+
+    let hash = |v: &dyn Fn(&mut std::collections::hash_map::DefaultHasher)| {
+        use std::hash::Hasher;
+        let mut h = std::collections::hash_map::DefaultHasher::new();
+        v(&mut h);
+        h.finish()
+    };
+    assert_eq!(
+        hash(&|h| (1, "a").hash_tuple(h)),
+        hash(&|h| std::hash::Hash::hash(&(1, "a"), h))
+    );
+
+    let wide = (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15);
+    let mut map = std::collections::HashMap::new();
+    map.insert(Ordered(wide), "wide");
+    assert_eq!(map.get(&Ordered(wide)), Some(&"wide"));
+}
+
+#[test]
+fn clone_tuple() {
+    let t = (1, String::from("a"), vec![2]);
+    assert_eq!(t.clone_tuple(), t);
+    assert_eq!((&t.1, &t.2).clone_tuple(), (String::from("a"), vec![2]));
+    let () = ().clone_tuple();
+
+    let wide = (
+        0,
+        1,
+        2,
+        3,
+        4,
+        5,
+        6,
+        7,
+        8,
+        9,
+        10,
+        11,
+        12,
+        13,
+        14,
+        String::new(),
+    );
+    let (a, b): ((_, _, _, _, _, _, _, _), (_, _, _, _, _, _, _, &String)) = (&wide).split();
+    assert_eq!(a.clone_tuple().join(b.clone_tuple()).15, wide.15);
+}
+
+#[test]
+fn display_join() {
+    assert_eq!(().display_join(", ").to_string(), "");
+    assert_eq!((1,).display_join(", ").to_string(), "1");
+    assert_eq!((1, 'a', "bc").display_join("").to_string(), "1abc");
+    assert_eq!(
+        format!("{:.1}", (1.25, 2.0).display_join(" / ")),
+        "1.2 / 2.0"
+    );
+
+    let wide = (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15);
+    assert_eq!(
+        wide.display_join(",").to_string(),
+        "0,1,2,3,4,5,6,7,8,9,10,11,12,13,14,15"
+    );
+}
+
+#[test]
+fn any_vec() {
+    use crate::convert::FromAnyVecErrorKind;
+    use std::any::Any;
+
+    let v = (1u8, String::from("two"), 3.0f64).into_any_vec();
+    assert_eq!(v.len(), 3);
+    assert_eq!(v[1].downcast_ref::<String>().unwrap(), "two");
+    assert_eq!(
+        <(u8, String, f64)>::from_any_vec(v).unwrap(),
+        (1, "two".to_string(), 3.0)
+    );
+
+    assert!(().into_any_vec().is_empty());
+    <()>::from_any_vec(vec![]).unwrap();
+
+    let err = <(u8, u16)>::from_any_vec(vec![Box::new(1u8)]).unwrap_err();
+    assert_eq!(
+        err.kind(),
+        FromAnyVecErrorKind::Count {
+            expected: 2,
+            found: 1
+        }
+    );
+    assert_eq!(err.to_string(), "expected 2 elements, found 1");
+
+    let v: Vec<Box<dyn Any>> = vec![Box::new(1u8), Box::new(2u8), Box::new(3u32)];
+    let err = <(u8, u16, u16)>::from_any_vec(v).unwrap_err();
+    assert_eq!(
+        err.kind(),
+        FromAnyVecErrorKind::Type {
+            index: 1,
+            expected: "u16"
+        }
+    );
+    let v = err.into_vec();
+    assert_eq!(<(u8, u8, u32)>::from_any_vec(v).unwrap(), (1, 2, 3));
+
+    let wide = (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15u8);
+    let v = wide.into_any_vec();
+    assert_eq!(v[15].downcast_ref::<u8>(), Some(&15));
+    let back = <(
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+    )>::from_any_vec(v);
+    assert_eq!(back.unwrap().into_array(), wide.into_array());
+}
+
+#[test]
+fn dyn_tuple() {
+    let mut t = DynTuple::new();
+    assert!(t.is_empty());
+    t.push(1u8);
+    t.push(String::from("two"));
+    t.push(3.0f64);
+    assert_eq!(t.len(), 3);
+    assert_eq!(t.get::<u8>(0), Some(&1));
+    assert_eq!(t.get::<u8>(1), None);
+    assert_eq!(t.get::<u8>(3), None);
+    t.get_mut::<String>(1).unwrap().push('!');
+
+    let t = match t.try_into_tuple::<(u8, String)>() {
+        Ok(_) => panic!("wrong length converted"),
+        Err(err) => DynTuple::from(err.into_vec()),
+    };
+    assert_eq!(
+        t.try_into_tuple::<(u8, String, f64)>().unwrap(),
+        (1, "two!".to_string(), 3.0)
+    );
+
+    let t = DynTuple::from_tuple((1u8, 'b'));
+    assert_eq!(t.get::<char>(1), Some(&'b'));
+    assert_eq!(t.into_vec().len(), 2);
+    DynTuple::default().try_into_tuple::<()>().unwrap();
+}
+
+#[test]
+fn idx_dyn() {
+    use crate::dynamic::{Elem1, Elem3};
+
+    let t = (1u8, String::from("a"), 2.5f64);
+    assert_eq!(t.idx_dyn(0), Some(Elem3::Elem0(&1)));
+    assert_eq!(t.idx_dyn(1), Some(Elem3::Elem1(&"a".to_string())));
+    assert_eq!(t.idx_dyn(2).map(|e| e.index()), Some(2));
+    assert_eq!(t.idx_dyn(3), None);
+
+    assert_eq!((1,).idx_dyn(0), Some(Elem1::Elem0(&1)));
+    assert!(().idx_dyn(0).is_none());
+
+    let wide = (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15u8);
+    assert_eq!(wide.idx_dyn(15).unwrap().index(), 15);
+    assert!(wide.idx_dyn(16).is_none());
+}
+
+#[test]
+fn for_each_dyn() {
+    use std::any::Any;
+
+    let mut seen = Vec::new();
+    (1u8, String::from("a"), 2.5f64).for_each_dyn(&mut |idx, elem: &dyn Any| {
+        let desc = if let Some(n) = elem.downcast_ref::<u8>() {
+            n.to_string()
+        } else if let Some(s) = elem.downcast_ref::<String>() {
+            s.clone()
+        } else {
+            "?".to_string()
+        };
+        seen.push((idx, desc));
+    });
+    assert_eq!(
+        seen,
+        [
+            (0, "1".to_string()),
+            (1, "a".to_string()),
+            (2, "?".to_string())
+        ]
+    );
+
+    let mut count = 0;
+    ().for_each_dyn(&mut |_, _| count += 1);
+    (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15).for_each_dyn(&mut |idx, elem| {
+        assert_eq!(elem.downcast_ref::<i32>(), Some(&(idx as i32)));
+        count += 1;
+    });
+    assert_eq!(count, 16);
+}
+
+#[test]
+fn as_dyn() {
+    trait Describe {
+        fn describe(&self) -> String;
+        fn bump(&mut self);
+    }
+    impl Describe for u8 {
+        fn describe(&self) -> String {
+            format!("u8 {}", self)
+        }
+        fn bump(&mut self) {
+            *self += 1
+        }
+    }
+    impl Describe for String {
+        fn describe(&self) -> String {
+            format!("string {:?}", self)
+        }
+        fn bump(&mut self) {
+            self.push('!')
+        }
+    }
+    crate::impl_tuple_dyn!(Describe);
+
+    let mut t = (1u8, String::from("a"), 2u8);
+    for elem in t.as_dyn_mut() {
+        elem.bump();
+    }
+    let descs: Vec<_> = t.as_dyn().iter().map(|elem| elem.describe()).collect();
+    assert_eq!(descs, ["u8 2", "string \"a!\"", "u8 3"]);
+
+    let unit: [&dyn Describe; 0] = ().as_dyn();
+    assert!(unit.is_empty());
+
+    // Borrowed, non-'static elements work too.
+    let s = String::from("b");
+    let borrowed = (&s,);
+    impl<T: Describe> Describe for &T {
+        fn describe(&self) -> String {
+            (**self).describe()
+        }
+        fn bump(&mut self) {}
+    }
+    assert_eq!(borrowed.as_dyn()[0].describe(), "string \"b\"");
+
+    let wide = (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15u8);
+    assert_eq!(wide.as_dyn()[15].describe(), "u8 15");
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn derive_tuple_ops() {
+    #[derive(Debug, PartialEq, TupleOps)]
+    struct Named<T: Clone> {
+        a: u8,
+        b: T,
+    }
+
+    #[derive(Debug, PartialEq, TupleOps)]
+    struct Positional(u8, &'static str, char);
+
+    #[derive(Debug, PartialEq, TupleOps)]
+    struct Unit;
+
+    let n = Named { a: 1, b: "x" };
+    assert_eq!(n.into_tuple(), (1, "x"));
+    assert_eq!(Named::from_tuple((2, 'y')), Named { a: 2, b: 'y' });
+    assert_eq!(Named { a: 1, b: 2.5 }.idx::<1>(), 2.5);
+
+    let p = Positional(1, "a", 'b');
+    let (left, right): ((u8,), (&str, char)) = p.split();
+    assert_eq!((left, right), ((1,), ("a", 'b')));
+    assert_eq!(
+        Positional::from_tuple((1, "a", 'b')),
+        Positional(1, "a", 'b')
+    );
+    assert_eq!(Positional(1, "a", 'b').join((true,)), (1, "a", 'b', true));
+
+    let () = Unit.into_tuple();
+    assert_eq!(Unit::from_tuple(()), Unit);
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn derive_tuple_like() {
+    #[derive(Debug, PartialEq, TupleLike)]
+    struct Row(pub (u32, String, bool));
+
+    #[derive(Debug, PartialEq, TupleLike)]
+    struct Pair<T> {
+        inner: (T, T),
+    }
+
+    let mut row = Row((1, String::from("a"), true));
+    row.as_tuple_mut().1.push('b');
+    assert_eq!(TupleIdx::<1>::idx(row.as_tuple()), "ab");
+    let (left, right): ((u32,), (String, bool)) = row.split();
+    assert_eq!((left, right), ((1,), ("ab".to_string(), true)));
+    assert_eq!(
+        Row::from_tuple((2, String::new(), false)).into_tuple(),
+        (2, String::new(), false)
+    );
+
+    let pair = Pair { inner: (1, 2) };
+    assert_eq!(pair.as_tuple().len(), 2);
+    assert_eq!(pair.join((3,)), (1, 2, 3));
+}
+
+#[test]
+fn impl_for_tuples() {
+    trait Stats {
+        fn count(&self) -> usize;
+        fn scale(&self) -> f64;
+        fn has_zero(&self) -> bool;
+        fn all_below(&self, limit: i64) -> bool;
+        fn describe(&self, sep: &str) -> String;
+        fn bump(&mut self, by: i64);
+    }
+
+    impl Stats for i64 {
+        fn count(&self) -> usize {
+            1
+        }
+        fn scale(&self) -> f64 {
+            *self as f64
+        }
+        fn has_zero(&self) -> bool {
+            *self == 0
+        }
+        fn all_below(&self, limit: i64) -> bool {
+            *self < limit
+        }
+        fn describe(&self, sep: &str) -> String {
+            format!("{}{}", self, sep)
+        }
+        fn bump(&mut self, by: i64) {
+            *self += by
+        }
+    }
+
+    crate::impl_for_tuples!(Stats {
+        fn count(&self) -> usize => sum;
+        fn scale(&self) -> f64 => product;
+        fn has_zero(&self) -> bool => any;
+        fn all_below(&self, limit: i64) -> bool => all;
+        fn describe(&self, sep: &str) -> String => fold(String::new(), |acc, s: String| acc + s.as_str());
+        fn bump(&mut self, by: i64) => each;
+    });
+
+    let mut t = (1i64, (2i64, 3i64), ());
+    assert_eq!(t.count(), 3);
+    assert_eq!(t.scale(), 6.0);
+    assert!(!t.has_zero());
+    assert!(t.all_below(4));
+    assert!(!t.all_below(3));
+    assert_eq!(t.describe(","), "1,2,3,");
+    t.bump(-1);
+    assert_eq!(t, (0, (1, 2), ()));
+    assert!(t.has_zero());
+
+    assert_eq!(().count(), 0);
+    assert_eq!(().scale(), 1.0);
+    assert!(().all_below(0));
+    assert_eq!(().describe(","), "");
+
+    let wide = (
+        0i64, 1i64, 2i64, 3i64, 4i64, 5i64, 6i64, 7i64, 8i64, 9i64, 10i64, 11i64, 12i64, 13i64,
+        14i64, 15i64,
+    );
+    assert_eq!(wide.count(), 16);
+}
+
+#[test]
+fn repr_c_tuple() {
+    crate::repr_c_tuple! {
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        struct Mixed(u8, u32, u16,);
+
+        #[derive(Debug, PartialEq)]
+        pub(crate) struct Empty();
+    }
+
+    assert_eq!(std::mem::offset_of!(Mixed, 0), 0);
+    assert_eq!(std::mem::offset_of!(Mixed, 1), 4);
+    assert_eq!(std::mem::offset_of!(Mixed, 2), 8);
+    assert_eq!(std::mem::size_of::<Mixed>(), 12);
+
+    let m = Mixed::from((1, 2, 3));
+    assert_eq!(m, Mixed(1, 2, 3));
+    assert_eq!(<(u8, u32, u16)>::from(m), (1, 2, 3));
+    assert_eq!(m.join(('a',)), (1, 2, 3, 'a'));
+    assert_eq!(Mixed::from_tuple((4, 5, 6)).idx::<1>(), 5);
+
+    assert_eq!(Empty::from(()), Empty());
+    let () = Empty().into();
+}
+
+#[test]
+fn layout_of() {
+    type T = (u8, u64, u16, [u8; 3]);
+    let layout = super::layout_of::<T>();
+    assert_eq!(layout.map(|(_, size, _)| size), [1, 8, 2, 3]);
+    assert_eq!(layout.map(|(_, _, align)| align), [1, 8, 2, 1]);
+
+    let t: T = (1, 2, 3, [4, 5, 6]);
+    let base = &t as *const T as usize;
+    assert_eq!(layout[0].0, &t.0 as *const _ as usize - base);
+    assert_eq!(layout[1].0, &t.1 as *const _ as usize - base);
+    assert_eq!(layout[2].0, &t.2 as *const _ as usize - base);
+    assert_eq!(layout[3].0, &t.3 as *const _ as usize - base);
+
+    assert_eq!(<()>::layout(), []);
+    assert_eq!(super::layout_of::<((), u32)>(), [(0, 0, 1), (0, 4, 4)]);
+
+    type Wide = (
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u32,
+    );
+    let total: usize = super::layout_of::<Wide>()
+        .iter()
+        .map(|(_, size, _)| size)
+        .sum();
+    assert_eq!(total, 19);
+}
+
+#[test]
+fn type_reflection() {
+    use std::any::TypeId;
+
+    assert_eq!(
+        <(u8, String, Option<bool>)>::type_ids(),
+        [
+            TypeId::of::<u8>(),
+            TypeId::of::<String>(),
+            TypeId::of::<Option<bool>>()
+        ]
+    );
+    assert_eq!(<()>::type_ids(), []);
+
+    fn names<'a>(_: &(&'a str, u8)) -> [&'static str; 2] {
+        // Doesn't require 'static elements.
+        <(&'a str, u8)>::type_names()
+    }
+    assert_eq!(names(&("a", 1)), ["&str", "u8"]);
+    assert_eq!(<()>::type_names(), [""; 0]);
+
+    type Wide = (
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        char,
+    );
+    assert_eq!(Wide::type_names()[15], "char");
+    assert_eq!(Wide::type_ids()[15], TypeId::of::<char>());
+}
+
+#[test]
+fn parse_tuple() {
+    use crate::convert::ParseTupleErrorKind;
+
+    assert_eq!(
+        super::parse_tuple::<(u32, f64, bool)>("1;2.5;true", ';').unwrap(),
+        (1, 2.5, true)
+    );
+    assert_eq!(
+        super::parse_tuple::<(String,)>("", ',').unwrap(),
+        (String::new(),)
+    );
+    assert_eq!(
+        super::parse_tuple::<(String, String)>(",", ',').unwrap(),
+        (String::new(), String::new())
+    );
+    super::parse_tuple::<()>("", ',').unwrap();
+
+    let err = super::parse_tuple::<(u32, u32)>("1,x", ',').unwrap_err();
+    assert_eq!(err.index(), 1);
+    assert!(matches!(err.kind(), ParseTupleErrorKind::Field(_)));
+    assert!(std::error::Error::source(&err).is_some());
+
+    let err = super::parse_tuple::<(u32, u32)>("1", ',').unwrap_err();
+    assert_eq!(err.index(), 1);
+    assert!(matches!(
+        err.kind(),
+        ParseTupleErrorKind::Count {
+            expected: 2,
+            found: 1
+        }
+    ));
+
+    let err = super::parse_tuple::<(u32, u32)>("1,2,3,4", ',').unwrap_err();
+    assert_eq!(err.index(), 2);
+    assert_eq!(err.to_string(), "expected 2 fields, found 4");
+
+    let err = super::parse_tuple::<()>("a,b", ',').unwrap_err();
+    assert_eq!(err.to_string(), "expected 0 fields, found 2");
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde_tuple() {
+    use crate::serde::SerdeTuple;
+
+    type Cols = (
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+    );
+
+    #[derive(::serde::Serialize, ::serde::Deserialize)]
+    struct Row {
+        #[serde(with = "crate::serde")]
+        cols: Cols,
+    }
+
+    let row = Row {
+        cols: (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15),
+    };
+    let json = serde_json::to_string(&row).unwrap();
+    assert_eq!(json, r#"{"cols":[0,1,2,3,4,5,6,7,8,9,10,11,12,13,14,15]}"#);
+    let back: Row = serde_json::from_str(&json).unwrap();
+    assert_eq!(serde_json::to_string(&back).unwrap(), json);
+
+    // Same representation as serde's own impls.
+    let t = (1, "a", true);
+    assert_eq!(
+        serde_json::to_string(&SerdeTuple(t)).unwrap(),
+        serde_json::to_string(&t).unwrap()
+    );
+    assert_eq!(serde_json::to_string(&SerdeTuple(())).unwrap(), "[]");
+    serde_json::from_str::<SerdeTuple<()>>("[]").unwrap();
+
+    assert!(serde_json::from_str::<SerdeTuple<(u8, u8)>>("[1]").is_err());
+    let err = serde_json::from_str::<SerdeTuple<(u8, u8)>>("[1,2,3]").unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("invalid length 3, expected a tuple of size 2"));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde_tuple_map() {
+    use crate::serde::SerdeTupleMap;
+
+    let json = serde_json::to_string(&SerdeTupleMap((1, "a", true))).unwrap();
+    assert_eq!(json, r#"{"0":1,"1":"a","2":true}"#);
+
+    let SerdeTupleMap(t): SerdeTupleMap<(u8, String, bool)> =
+        serde_json::from_str(r#"{"2":false,"0":7,"1":"b"}"#).unwrap();
+    assert_eq!(t, (7, "b".to_string(), false));
+
+    assert_eq!(serde_json::to_string(&SerdeTupleMap(())).unwrap(), "{}");
+    serde_json::from_str::<SerdeTupleMap<()>>("{}").unwrap();
+
+    let missing = serde_json::from_str::<SerdeTupleMap<(u8, u8)>>(r#"{"0":1}"#);
+    assert!(missing
+        .unwrap_err()
+        .to_string()
+        .contains("missing field `1`"));
+    let dup = serde_json::from_str::<SerdeTupleMap<(u8, u8)>>(r#"{"0":1,"1":2,"0":3}"#);
+    assert!(dup.unwrap_err().to_string().contains("duplicate field `0`"));
+    assert!(serde_json::from_str::<SerdeTupleMap<(u8, u8)>>(r#"{"0":1,"1":2,"2":3}"#).is_err());
+    assert!(serde_json::from_str::<SerdeTupleMap<(u8,)>>(r#"{"x":1}"#).is_err());
+}
+
+#[cfg(feature = "schemars")]
+#[test]
+fn json_schema() {
+    use crate::serde::{SerdeTuple, SerdeTupleMap};
+    use schemars::{schema_for, JsonSchema};
+    use serde_json::json;
+
+    // Matches schemars' own impl where it has one.
+    assert_eq!(
+        schema_for!(SerdeTuple<(u8, String)>),
+        schema_for!((u8, String))
+    );
+    assert_eq!(
+        <SerdeTuple<(u8, String)>>::schema_name(),
+        <(u8, String)>::schema_name()
+    );
+
+    let schema = schema_for!(SerdeTuple<()>);
+    assert_eq!(schema.get("prefixItems"), Some(&json!([])));
+    assert_eq!(schema.get("maxItems"), Some(&json!(0)));
+
+    let schema = schema_for!(SerdeTupleMap<(u8, bool)>);
+    assert_eq!(
+        schema.get("properties"),
+        Some(&json!({
+            "0": {"type": "integer", "format": "uint8", "minimum": 0, "maximum": 255},
+            "1": {"type": "boolean"},
+        }))
+    );
+    assert_eq!(schema.get("required"), Some(&json!(["0", "1"])));
+}
+
+#[cfg(feature = "rkyv")]
+#[test]
+fn archive() {
+    use archive::{ArchivedTuple0, AsTuple};
+    use rkyv::rancor::Error;
+
+    type Cols = (
+        u8,
+        u16,
+        u32,
+        u64,
+        i8,
+        i16,
+        i32,
+        i64,
+        u8,
+        u16,
+        u32,
+        u64,
+        i8,
+        i16,
+        i32,
+        i64,
+    );
+
+    #[derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)]
+    struct Row {
+        #[rkyv(with = AsTuple)]
+        cols: Cols,
+        #[rkyv(with = AsTuple)]
+        strs: (String, Vec<u8>),
+        #[rkyv(with = AsTuple)]
+        unit: (),
+    }
+
+    let row = Row {
+        cols: (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15),
+        strs: ("abc".to_string(), vec![1, 2]),
+        unit: (),
+    };
+    let bytes = rkyv::to_bytes::<Error>(&row).unwrap();
+    let archived = rkyv::access::<ArchivedRow, Error>(&bytes).unwrap();
+    assert_eq!(archived.cols.3, 3);
+    assert_eq!(archived.cols.15, 15);
+    assert_eq!(archived.strs.0, "abc");
+    assert_eq!(archived.strs.1.as_slice(), &[1, 2]);
+    assert_eq!(archived.unit, ArchivedTuple0());
+
+    let back: Row = rkyv::deserialize::<_, Error>(archived).unwrap();
+    assert!(wide::Ordered(back.cols) == wide::Ordered(row.cols));
+    assert_eq!(back.strs, row.strs);
+}
+
+#[cfg(feature = "proptest")]
+#[test]
+fn tuple_strategy() {
+    use proptest::prelude::*;
+    use proptest::strategy::ValueTree;
+    use proptest::test_runner::{TestError, TestRunner};
+    use strategy::{TupleStrategy, TupleValue};
+
+    let strat = TupleStrategy((
+        0..100u32,
+        0..100u32,
+        0..100u32,
+        0..100u32,
+        0..100u32,
+        0..100u32,
+        0..100u32,
+        0..100u32,
+        0..100u32,
+        0..100u32,
+        0..100u32,
+        0..100u32,
+        0..100u32,
+        0..100u32,
+    ));
+
+    // Shrinks each element to the smallest failing value.
+    let mut runner = TestRunner::deterministic();
+    let res = runner.run(&strat, |TupleValue(t)| {
+        prop_assert!(t.0 < 10 || t.13 < 20);
+        Ok(())
+    });
+    match res {
+        Err(TestError::Fail(_, TupleValue(t))) => {
+            assert_eq!((t.0, t.1, t.12, t.13), (10, 0, 0, 20));
+        }
+        other => panic!("unexpected result {other:?}"),
+    }
+
+    let v = any::<TupleValue<(bool, u8)>>()
+        .new_tree(&mut runner)
+        .unwrap()
+        .current();
+    assert_eq!(format!("{:?}", TupleValue((1, 'a'))), "(1, 'a')");
+    let _: (bool, u8) = v.into_inner();
+
+    let mut unit = TupleStrategy(()).new_tree(&mut runner).unwrap();
+    assert_eq!(unit.current(), TupleValue(()));
+    assert!(!unit.simplify());
+    assert!(!unit.complicate());
+}
+
+#[cfg(feature = "quickcheck")]
+#[test]
+fn arbitrary_tuple() {
+    use crate::quickcheck::ArbitraryTuple;
+    use ::quickcheck::{Arbitrary, Gen};
+
+    let t: ArbitraryTuple<(u8, bool, String)> = Arbitrary::arbitrary(&mut Gen::new(10));
+    let _: (u8, bool, String) = t.into_inner();
+
+    // Each element shrinks in turn, leaving the others unchanged.
+    let t = ArbitraryTuple((2u8, 1u8));
+    let shrunk: Vec<_> = t.shrink().map(ArbitraryTuple::into_inner).collect();
+    assert_eq!(shrunk, [(0, 1), (1, 1), (2, 0)]);
+    assert_eq!(ArbitraryTuple(()).shrink().count(), 0);
+
+    type Wide = (u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8);
+    let wide: ArbitraryTuple<Wide> = ArbitraryTuple((1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1));
+    assert_eq!(wide.shrink().count(), 2);
+    assert_eq!(
+        format!("{wide:?}"),
+        "(1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1)"
+    );
+}
+
+#[cfg(feature = "arbitrary")]
+#[test]
+fn arbitrary_fuzz_tuple() {
+    use crate::arbitrary::ArbitraryTuple;
+    use ::arbitrary::{Arbitrary, Unstructured};
+
+    // Consumes input the same way as arbitrary's impls for tuples.
+    let data = [1, 2, 3, 4, 5, 6, 7, 8];
+    let ArbitraryTuple(t) =
+        ArbitraryTuple::<(u8, u16, Vec<u8>)>::arbitrary_take_rest(Unstructured::new(&data))
+            .unwrap();
+    assert_eq!(
+        t,
+        <(u8, u16, Vec<u8>)>::arbitrary_take_rest(Unstructured::new(&data)).unwrap()
+    );
+    assert_eq!(t.0, 1);
+
+    assert_eq!(
+        <ArbitraryTuple<(u8, u16)>>::size_hint(0),
+        <(u8, u16)>::size_hint(0)
+    );
+    ArbitraryTuple::<()>::arbitrary_take_rest(Unstructured::new(&data)).unwrap();
+
+    type Wide = (u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8);
+    let data: Vec<u8> = (0..14).collect();
+    let wide = ArbitraryTuple::<Wide>::arbitrary(&mut Unstructured::new(&data)).unwrap();
+    assert_eq!(
+        format!("{wide:?}"),
+        "(0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13)"
+    );
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn rand_tuple() {
+    use crate::rand::{random_tuple, StandardTuple, TupleDistribution};
+    use ::rand::distr::{Distribution, Uniform};
+    use ::rand::rngs::SmallRng;
+    use ::rand::SeedableRng;
+
+    // Same sequence as rand's own tuple impls.
+    let (a, b): (u32, bool) = random_tuple(&mut SmallRng::seed_from_u64(3));
+    let (c, d): (u32, bool) = ::rand::RngExt::random(&mut SmallRng::seed_from_u64(3));
+    assert_eq!((a, b), (c, d));
+
+    let mut rng = SmallRng::seed_from_u64(3);
+    let () = StandardTuple.sample(&mut rng);
+
+    let dist = TupleDistribution((
+        Uniform::new(10u8, 20).unwrap(),
+        Uniform::new_inclusive(-1i32, -1).unwrap(),
+    ));
+    for (x, y) in dist.sample_iter(&mut rng).take(100) {
+        assert!((10..20).contains(&x));
+        assert_eq!(y, -1);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+#[test]
+fn zeroize_tuple() {
+    use crate::zeroize::{TupleZeroize, ZeroizingTuple};
+    use ::zeroize::Zeroize;
+
+    let mut t = (1u8, String::from("secret"), vec![1u32, 2], [3u8; 4]);
+    t.zeroize_each();
+    assert_eq!(t, (0, String::new(), vec![], [0; 4]));
+    ().zeroize_each();
+
+    let mut wide = (
+        1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+    );
+    wide.zeroize_each();
+    assert_eq!(wide.0 + wide.13, 0);
+
+    let mut z = ZeroizingTuple::new((5u32, String::from("pw")));
+    z.0 += 1;
+    assert_eq!(*z, (6, String::from("pw")));
+    z.zeroize();
+    assert_eq!(*z, (0, String::new()));
+}
+
+#[cfg(feature = "approx")]
+#[test]
+fn approx_tuple() {
+    use crate::approx::ApproxTuple;
+    use ::approx::{
+        abs_diff_eq, assert_abs_diff_eq, assert_relative_eq, assert_ulps_eq, relative_ne,
+    };
+
+    let a = ApproxTuple((0.1f64 + 0.2, 1.0f64, -2.0f64));
+    let b = ApproxTuple((0.3f64, 1.0f64, -2.0f64));
+    assert!(a != b);
+    assert_abs_diff_eq!(a, b);
+    assert_relative_eq!(a, b);
+    assert_ulps_eq!(a, b);
+
+    let c = ApproxTuple((0.3f64, 1.1f64, -2.0f64));
+    assert!(relative_ne!(a, c));
+    assert!(abs_diff_eq!(a, c, epsilon = 0.2));
+
+    assert_relative_eq!(ApproxTuple(()), ApproxTuple(()));
+
+    let wide = ApproxTuple((
+        1.0f32, 1.0f32, 1.0f32, 1.0f32, 1.0f32, 1.0f32, 1.0f32, 1.0f32, 1.0f32, 1.0f32, 1.0f32,
+        1.0f32, 1.0f32, 1.0f32,
+    ));
+    assert_relative_eq!(wide, wide);
+}
+
+#[test]
+fn assert_tuple_eq() {
+    use std::panic::catch_unwind;
+
+    use fmt::{ElemDiff, TupleDiff};
+
+    crate::assert_tuple_eq!((1, 'a', "b"), (1, 'a', "b"));
+    crate::assert_tuple_eq!((), ());
+    crate::assert_tuple_eq!((1,), (1,), "with a message {}", 1);
+
+    assert_eq!(
+        (1, 2, 3).diff(&(1, 5, 4)),
+        [
+            ElemDiff {
+                index: 1,
+                left: "2".into(),
+                right: "5".into()
+            },
+            ElemDiff {
+                index: 2,
+                left: "3".into(),
+                right: "4".into()
+            },
+        ]
+    );
+
+    let err = catch_unwind(|| {
+        crate::assert_tuple_eq!(
+            (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, "x"),
+            (0, 1, 2, 3, 4, 5, 6, 7, 8, 0, 10, 11, 12, "y"),
+            "row {}",
+            3
+        )
+    })
+    .unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<String>().unwrap(),
+        "assertion `left == right` failed: row 3\n\
+         2 of 14 elements differ\n\
+         at index 9:\n  left: 9\n right: 0\n\
+         at index 13:\n  left: \"x\"\n right: \"y\""
+    );
+}
+
+#[cfg(feature = "frunk")]
+#[test]
+fn hlist() {
+    use crate::hlist::{HListIntoTuple, TupleIntoHList};
+    use frunk_core::hlist::HNil;
+
+    let h = (1u8, "a", 'b').into_hlist();
+    assert_eq!(h, frunk_core::hlist![1u8, "a", 'b']);
+    assert_eq!(h.into_tuple(), (1, "a", 'b'));
+
+    assert_eq!(().into_hlist(), HNil);
+    let () = HNil.into_tuple();
+
+    let wide = (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15);
+    let h = wide.into_hlist();
+    assert_eq!(h.head, 0);
+    assert_eq!(h.into_tuple().15, 15);
+}
+
+#[cfg(feature = "pyo3")]
+#[test]
+fn python_tuple() {
+    use crate::python::PythonTuple;
+    use pyo3::prelude::*;
+    use pyo3::types::PyTuple;
+
+    type Wide = (
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        String,
+        bool,
+    );
+
+    Python::initialize();
+    Python::attach(|py| {
+        let wide: Wide = (
+            0,
+            1,
+            2,
+            3,
+            4,
+            5,
+            6,
+            7,
+            8,
+            9,
+            10,
+            11,
+            12,
+            13,
+            "x".into(),
+            true,
+        );
+        let obj = PythonTuple(wide.clone()).into_pyobject(py).unwrap();
+        assert_eq!(obj.len(), 16);
+        assert_eq!(obj.get_item(14).unwrap().extract::<String>().unwrap(), "x");
+
+        let PythonTuple(back) = obj.extract::<PythonTuple<Wide>>().unwrap();
+        assert!(back.eq_tuple(&wide));
+
+        let err = obj.extract::<PythonTuple<(u8, u8)>>().unwrap_err();
+        assert!(err.is_instance_of::<pyo3::exceptions::PyValueError>(py));
+        assert!(obj
+            .get_item(0)
+            .unwrap()
+            .extract::<PythonTuple<(u8,)>>()
+            .is_err());
+
+        let unit = PythonTuple(()).into_pyobject(py).unwrap();
+        assert!(unit.is(PyTuple::empty(py)));
+        let PythonTuple(()) = unit.extract().unwrap();
+    });
+}
+
+// wasm-bindgen imports can only be called on wasm targets.
+#[cfg(all(feature = "wasm", target_arch = "wasm32"))]
+#[test]
+fn js_array() {
+    use crate::wasm::{FromJsArrayError, TupleFromJsArray, TupleIntoJsArray};
+    use wasm_bindgen::JsValue;
+
+    let array = (1.5, String::from("a"), true).into_js_array();
+    assert_eq!(array.length(), 3);
+    let (num, s, b) = <(f64, String, JsValue)>::from_js_array(&array).unwrap();
+    assert_eq!((num, s.as_str(), b), (1.5, "a", JsValue::TRUE));
+
+    let err = <(f64, f64)>::from_js_array(&array).unwrap_err();
+    assert!(matches!(
+        err,
+        FromJsArrayError::Count {
+            expected: 2,
+            found: 3
+        }
+    ));
+    let err = <(f64, f64, JsValue)>::from_js_array(&array).unwrap_err();
+    assert!(matches!(err, FromJsArrayError::Elem { index: 1, .. }));
+
+    assert_eq!(().into_js_array().length(), 0);
+}
+
+#[cfg(feature = "serde_json")]
+#[test]
+fn json_array() {
+    use crate::json::{JsonArrayError, TupleFromJson, TupleToJson};
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    let value = (1u8, "a", Some(2.5)).to_json_array().unwrap();
+    assert_eq!(value, json!([1, "a", 2.5]));
+    assert_eq!(
+        <(u8, String, Option<f64>)>::from_json_array(value).unwrap(),
+        (1, "a".to_string(), Some(2.5))
+    );
+
+    assert_eq!(().to_json_array().unwrap(), json!([]));
+    <()>::from_json_array(json!([])).unwrap();
+
+    let err = <(u8,)>::from_json_array(json!({"0": 1})).unwrap_err();
+    assert!(matches!(err, JsonArrayError::NotArray));
+    let err = <(u8,)>::from_json_array(json!([1, 2])).unwrap_err();
+    assert!(matches!(
+        err,
+        JsonArrayError::Count {
+            expected: 1,
+            found: 2
+        }
+    ));
+    let err = <(u8, u8)>::from_json_array(json!([1, 256])).unwrap_err();
+    assert_eq!(err.index(), Some(1));
+    assert!(std::error::Error::source(&err).is_some());
+
+    // Maps with non-string keys can't be converted to JSON values.
+    let bad: HashMap<(u8, u8), u8> = [((1, 2), 3)].into_iter().collect();
+    let err = (1, bad).to_json_array().unwrap_err();
+    assert_eq!(err.index(), Some(1));
+
+    let wide = (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, "x");
+    let value = wide.to_json_array().unwrap();
+    assert_eq!(value[15], "x");
+    type Wide = (
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        String,
+    );
+    assert_eq!(Wide::from_json_array(value).unwrap().15, "x");
+}
+
+#[cfg(feature = "csv")]
+#[test]
+fn csv_record() {
+    use crate::convert::ParseTupleErrorKind;
+    use crate::csv::{TupleFromRecord, TupleToRecord};
+    use ::csv::StringRecord;
+
+    let record = StringRecord::from(vec!["1", "a,b", "true"]);
+    let t = <(u32, String, bool)>::from_record(&record).unwrap();
+    assert_eq!(t, (1, "a,b".to_string(), true));
+    assert_eq!(t.to_record(), record);
+
+    let err = <(u32, u32, bool)>::from_record(&record).unwrap_err();
+    assert_eq!(err.index(), 1);
+    assert!(matches!(err.kind(), ParseTupleErrorKind::Field(_)));
+    let err = <(u32, String)>::from_record(&record).unwrap_err();
+    assert!(matches!(
+        err.kind(),
+        ParseTupleErrorKind::Count {
+            expected: 2,
+            found: 3
+        }
+    ));
+
+    assert_eq!(().to_record(), StringRecord::new());
+    <()>::from_record(&StringRecord::new()).unwrap();
+
+    let wide = (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 'x');
+    let record = wide.to_record();
+    assert_eq!(&record[15], "x");
+    type Wide = (
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        char,
+    );
+    assert_eq!(Wide::from_record(&record).unwrap().15, 'x');
+
+    let mut writer = ::csv::Writer::from_writer(vec![]);
+    writer.write_record(&record).unwrap();
+    let out = String::from_utf8(writer.into_inner().unwrap()).unwrap();
+    assert_eq!(out, "0,1,2,3,4,5,6,7,8,9,10,11,12,13,14,x\n");
+}
+
+#[cfg(feature = "generic_array")]
+#[test]
+fn generic_array() {
+    use crate::generic_array::{TupleGenericArray, TupleTypenum};
+    use ::generic_array::typenum::{Unsigned, U0, U16, U3};
+    use ::generic_array::GenericArray;
+
+    assert_eq!(<(u8, &str, char) as TupleTypenum>::Len::USIZE, 3);
+    assert_eq!(<() as TupleTypenum>::Len::USIZE, 0);
+
+    let arr: GenericArray<i32, U3> = (1, 2, 3).into_generic_array();
+    assert_eq!(arr, GenericArray::from_array([1, 2, 3]));
+    assert_eq!(<(i32, i32, i32)>::from_generic_array(arr), (1, 2, 3));
+
+    let empty: GenericArray<u8, U0> = <()>::into_generic_array(());
+    assert!(empty.is_empty());
+
+    let wide = (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15);
+    let arr: GenericArray<u8, U16> = wide.into_generic_array();
+    assert_eq!(arr[15], 15);
+    type Wide = (
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+    );
+    assert_eq!(
+        Wide::from_generic_array(arr).into_array(),
+        wide.into_array()
+    );
+}
+
+#[cfg(feature = "smallvec")]
+#[test]
+fn smallvec() {
+    use crate::smallvec::TupleSmallVec;
+    use ::smallvec::{smallvec, SmallVec};
+
+    let v = (1, 2, 3).into_smallvec();
+    assert!(!v.spilled());
+    assert_eq!(v.inline_size(), 3);
+    assert_eq!(v.as_slice(), &[1, 2, 3]);
+    assert_eq!(<(i32, i32, i32)>::from_smallvec(v), Ok((1, 2, 3)));
+
+    let spilled: SmallVec<[String; 1]> = smallvec!["a".to_string(), "b".to_string()];
+    assert!(spilled.spilled());
+    assert_eq!(
+        <(String, String)>::from_smallvec(spilled),
+        Ok(("a".to_string(), "b".to_string()))
+    );
+
+    let err = <(u8, u8)>::from_smallvec(SmallVec::<[u8; 4]>::from_slice(&[1, 2, 3])).unwrap_err();
+    assert_eq!((err.expected(), err.found()), (2, 3));
+
+    let empty: SmallVec<[u8; 0]> = <()>::into_smallvec(());
+    assert!(empty.is_empty());
+    assert_eq!(<()>::from_smallvec(SmallVec::<[u8; 0]>::new()), Ok(()));
+}
+
+#[cfg(feature = "arrayvec")]
+#[test]
+fn arrayvec() {
+    use crate::arrayvec::TupleArrayVec;
+    use ::arrayvec::ArrayVec;
+
+    let v = (1, 2, 3).into_arrayvec();
+    assert!(v.is_full());
+    assert_eq!(v.as_slice(), &[1, 2, 3]);
+    assert_eq!(<(i32, i32, i32)>::from_arrayvec(v), Ok((1, 2, 3)));
+
+    let mut v = ArrayVec::<String, 4>::new();
+    v.push("a".to_string());
+    v.push("b".to_string());
+    assert_eq!(
+        <(String, String)>::from_arrayvec(v),
+        Ok(("a".to_string(), "b".to_string()))
+    );
+
+    let err = <(u8, u8)>::from_arrayvec(ArrayVec::from([1u8, 2, 3])).unwrap_err();
+    assert_eq!((err.expected(), err.found()), (2, 3));
+
+    let empty: ArrayVec<u8, 0> = ().into_arrayvec();
+    assert!(empty.is_empty());
+    assert_eq!(<()>::from_arrayvec(ArrayVec::<u8, 2>::new()), Ok(()));
+}
+
+#[cfg(feature = "heapless")]
+#[test]
+fn heapless() {
+    use crate::heapless::TupleHeaplessVec;
+
+    let v = (1, 2, 3).into_heapless_vec();
+    assert!(v.is_full());
+    assert_eq!(v.as_slice(), &[1, 2, 3]);
+    assert_eq!(<(i32, i32, i32)>::from_heapless_vec(v), Ok((1, 2, 3)));
+
+    let mut v = ::heapless::Vec::<String, 4>::new();
+    v.push("a".to_string()).unwrap();
+    v.push("b".to_string()).unwrap();
+    assert_eq!(
+        <(String, String)>::from_heapless_vec(v),
+        Ok(("a".to_string(), "b".to_string()))
+    );
+
+    let v: ::heapless::Vec<u8, 4> = ::heapless::Vec::from_slice(&[1, 2, 3]).unwrap();
+    let err = <(u8, u8)>::from_heapless_vec(v).unwrap_err();
+    assert_eq!((err.expected(), err.found()), (2, 3));
+
+    let wide = (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15);
+    let v: ::heapless::Vec<u8, 16> = wide.into_heapless_vec();
+    assert_eq!(v[15], 15);
+}
+
+#[cfg(feature = "ndarray")]
+#[test]
+fn ndarray() {
+    use crate::ndarray::TupleNdarray;
+    use ::ndarray::{array, Array1};
+
+    let a = (1.0, 2.0, 3.0).into_array1();
+    assert_eq!(a, array![1.0, 2.0, 3.0]);
+    assert_eq!(
+        <(f64, f64, f64)>::from_array_view(a.view()),
+        Ok((1.0, 2.0, 3.0))
+    );
+
+    let m = array![[1, 2, 3], [4, 5, 6]];
+    assert_eq!(<(i32, i32)>::from_array_view(m.column(2)), Ok((3, 6)));
+    assert_eq!(<(i32, i32, i32)>::from_array_view(m.row(1)), Ok((4, 5, 6)));
+
+    let err = <(i32, i32)>::from_array_view(m.row(0)).unwrap_err();
+    assert_eq!((err.expected(), err.found()), (2, 3));
+
+    let empty: Array1<u8> = ().into_array1();
+    assert!(empty.is_empty());
+    assert_eq!(<()>::from_array_view(empty.view()), Ok(()));
+}
+
+#[cfg(feature = "nalgebra")]
+#[test]
+fn nalgebra() {
+    use crate::nalgebra::TupleNalgebra;
+    use ::nalgebra::{Point2, SVector, Vector1, Vector3};
+
+    let v = (1, 2, 3).into_svector();
+    assert_eq!(v, Vector3::new(1, 2, 3));
+    assert_eq!(<(i32, i32, i32)>::from_svector(v * 2), (2, 4, 6));
+
+    let p = (1.5, -1.0).into_point();
+    assert_eq!(p, Point2::new(1.5, -1.0));
+    assert_eq!(<(f32, f32)>::from_point(p), (1.5, -1.0));
+
+    assert_eq!((7u8,).into_svector(), Vector1::new(7));
+
+    let wide = (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15);
+    let v: SVector<u32, 16> = wide.into_svector();
+    assert_eq!(v.sum(), 120);
+    type Wide = (
+        u32,
+        u32,
+        u32,
+        u32,
+        u32,
+        u32,
+        u32,
+        u32,
+        u32,
+        u32,
+        u32,
+        u32,
+        u32,
+        u32,
+        u32,
+        u32,
+    );
+    assert_eq!(Wide::from_svector(v).into_array(), wide.into_array());
+}
+
+#[cfg(feature = "glam")]
+#[test]
+fn glam() {
+    use crate::glam::TupleGlam;
+    use ::glam::{DVec4, IVec3, U8Vec2, Vec2};
+
+    let v = (1.0f32, 2.0).into_glam();
+    assert_eq!(v, Vec2::new(1.0, 2.0));
+    assert_eq!(<(f32, f32)>::from_glam(v.perp()), (-2.0, 1.0));
+
+    assert_eq!((1i32, 2, 3).into_glam() * 2, IVec3::new(2, 4, 6));
+    assert_eq!(
+        (0.5f64, 1.0, 1.5, 2.0).into_glam(),
+        DVec4::new(0.5, 1.0, 1.5, 2.0)
+    );
+    assert_eq!(<(u8, u8)>::from_glam(U8Vec2::new(7, 9)), (7, 9));
+}
+
+#[cfg(feature = "tracing")]
+#[test]
+fn tracing_fields() {
+    use crate::tracing::TupleRecord;
+    use ::tracing::field::{Empty, Field, Visit};
+    use ::tracing::span::{Attributes, Id, Record};
+    use ::tracing::{info_span, Event, Metadata, Subscriber};
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Clone, Default)]
+    struct Recorder(Arc<Mutex<Vec<(String, String)>>>);
+
+    impl Visit for Recorder {
+        fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+            let mut fields = self.0.lock().unwrap();
+            fields.push((field.name().to_string(), format!("{value:?}")));
+        }
+    }
+
+    impl Subscriber for Recorder {
+        fn enabled(&self, _: &Metadata<'_>) -> bool {
+            true
+        }
+        fn new_span(&self, _: &Attributes<'_>) -> Id {
+            Id::from_u64(1)
+        }
+        fn record(&self, _: &Id, values: &Record<'_>) {
+            values.record(&mut self.clone());
+        }
+        fn record_follows_from(&self, _: &Id, _: &Id) {}
+        fn event(&self, _: &Event<'_>) {}
+        fn enter(&self, _: &Id) {}
+        fn exit(&self, _: &Id) {}
+    }
+
+    let recorder = Recorder::default();
+    ::tracing::subscriber::with_default(recorder.clone(), || {
+        let span = info_span!("test", "t.0" = Empty, "t.1" = Empty, "t.2" = Empty);
+        ("a", 2u16, true).record_fields(&span, "t");
+        // undeclared fields are dropped
+        (1, 2).record_fields(&span, "u");
+    });
+
+    let fields = recorder.0.lock().unwrap();
+    assert_eq!(
+        *fields,
+        [
+            ("t.0".to_string(), "\"a\"".to_string()),
+            ("t.1".to_string(), "2".to_string()),
+            ("t.2".to_string(), "true".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn cons() {
+    let c = (1, 'a', "b").into_cons();
+    assert_eq!(c, (1, ('a', ("b", ()))));
+    assert_eq!(c.into_tuple(), (1, 'a', "b"));
+
+    let () = ().into_cons();
+    let () = ().into_tuple();
+    assert_eq!((1,).into_cons(), (1, ()));
+    assert_eq!((1, ()).into_tuple(), (1,));
+
+    type Wide = (
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+    );
+    let wide: Wide = (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15);
+    let c: Cons<Wide> = wide.into_cons();
+    assert_eq!(c.1 .1 .0, 2);
+    assert_eq!(c.into_tuple().15, 15);
+}
@@ -0,0 +1,39 @@
+//! Recording tuples as structured [`tracing`] fields.
+//!
+//! [`TupleRecord::record_fields`] records each element of a tuple as a
+//! separate field on a span, named by index, such as `t.0`, `t.1` ..., rather
+//! than a single `Debug` string. As with any `tracing` field, these must be
+//! declared when the span is created, typically as
+//! [`Empty`](::tracing::field::Empty):
+//! ```rust
+//! # use tracing::{field::Empty, info_span};
+//! # use tuplestructops::tracing::TupleRecord;
+//! let span = info_span!("request", "t.0" = Empty, "t.1" = Empty, "t.2" = Empty);
+//! ("GET", 200u16, true).record_fields(&span, "t");
+//! ```
+use ::tracing::{field::Value, Span};
+
+use super::*;
+
+/// Record the elements of a tuple as fields of a span.
+pub trait TupleRecord: seal::Sealed {
+    /// Record each element on `span` as a field named `{name}.{index}`.
+    /// Elements without a matching field declared on the span are ignored.
+    fn record_fields(&self, span: &Span, name: &str);
+}
+
+macro_rules! impl_record {
+    ($n:literal) => {
+        seq!(J in 0..$n {
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<#(T~J: Value,)*> TupleRecord for (#(T~J,)*) {
+                #[allow(unused_variables)]
+                fn record_fields(&self, span: &Span, name: &str) {
+                    #(span.record(format!("{name}.{}", J).as_str(), &self.J);)*
+                }
+            }
+        });
+    };
+}
+
+for_each_tuple!(impl_record);
@@ -0,0 +1,270 @@
+use super::*;
+
+/// Convert a tuple of `Option`s into an `Option` of a tuple.
+pub trait TupleTranspose: seal::Sealed {
+    /// Tuple of the `Some` values.
+    type Output;
+
+    /// Return `Some` tuple of all the values if every element is `Some`,
+    /// otherwise `None`.
+    /// ```rust
+    /// # use tuplestructops::TupleTranspose;
+    /// assert_eq!((Some(1), Some('a')).transpose(), Some((1, 'a')));
+    /// assert_eq!((Some(1), None::<char>).transpose(), None);
+    /// assert_eq!((&(Some(1), Some('a'))).transpose(), Some((&1, &'a')));
+    /// ```
+    /// The unit tuple is always `Some(())`.
+    fn transpose(self) -> Option<Self::Output>;
+}
+
+/// Convert a tuple of `Result`s with the same error type `E` into a `Result`
+/// of a tuple.
+pub trait TupleTryCollect<E>: seal::Sealed {
+    /// Tuple of the `Ok` values.
+    type Output;
+
+    /// Return `Ok` tuple of all the values if every element is `Ok`, otherwise
+    /// the first error.
+    /// ```rust
+    /// # use tuplestructops::TupleTryCollect;
+    /// let fields = ("42".parse::<u8>(), "x".parse::<u8>(), "300".parse::<u8>());
+    /// assert_eq!(fields.try_collect(), Err("x".parse::<u8>().unwrap_err()));
+    ///
+    /// let fields = ("1".parse::<i32>(), "2".parse::<i32>());
+    /// assert_eq!(fields.try_collect(), Ok((1, 2)));
+    /// ```
+    fn try_collect(self) -> Result<Self::Output, E>;
+}
+
+/// Convert a tuple of `Result`s with the same error type `E` into a `Result`
+/// of a tuple, accumulating all the errors.
+pub trait TupleValidate<E>: seal::Sealed {
+    /// Tuple of the `Ok` values.
+    type Output;
+
+    /// Return `Ok` tuple of all the values if every element is `Ok`, otherwise
+    /// all the errors in order. Unlike [`TupleTryCollect::try_collect`] this
+    /// doesn't stop at the first error.
+    /// ```rust
+    /// # use tuplestructops::TupleValidate;
+    /// let fields = ("42".parse::<u8>(), "x".parse::<u8>(), "300".parse::<u8>());
+    /// assert_eq!(fields.validate().unwrap_err().len(), 2);
+    ///
+    /// let fields = ("1".parse::<i32>(), "2".parse::<i32>());
+    /// assert_eq!(fields.validate(), Ok((1, 2)));
+    /// ```
+    fn validate(self) -> Result<Self::Output, Vec<E>>;
+}
+
+/// Distribute an `Option` or `Result` over the elements of a tuple. This is the
+/// converse of [`TupleTranspose`] and [`TupleTryCollect`].
+///
+/// See [`unzip_option`] and [`unzip_result`] for more convenient forms.
+pub trait TupleUnzip: seal::Sealed + Sized {
+    /// Tuple of `Option`s of each element.
+    type Options;
+    /// Tuple of `Result`s of each element.
+    type Results<E>;
+
+    /// Distribute an `Option` over a tuple.
+    fn unzip_option(opt: Option<Self>) -> Self::Options;
+
+    /// Distribute a `Result` over a tuple. An error is cloned into every
+    /// element.
+    fn unzip_result<E: Clone>(res: Result<Self, E>) -> Self::Results<E>;
+}
+
+/// Distribute an `Option` of a tuple into a tuple of `Option`s, generalizing
+/// [`Option::unzip`] to all tuple sizes.
+/// ```rust
+/// # use tuplestructops::unzip_option;
+/// assert_eq!(unzip_option(Some((1, 'a', "x"))), (Some(1), Some('a'), Some("x")));
+/// assert_eq!(unzip_option(None::<(u8, char)>), (None, None));
+/// ```
+pub fn unzip_option<T: TupleUnzip>(opt: Option<T>) -> T::Options {
+    T::unzip_option(opt)
+}
+
+/// Distribute a `Result` of a tuple into a tuple of `Result`s. An error is
+/// cloned into every element.
+/// ```rust
+/// # use tuplestructops::unzip_result;
+/// assert_eq!(unzip_result(Ok::<_, ()>((1, 'a'))), (Ok(1), Ok('a')));
+/// assert_eq!(unzip_result(Err::<(u8, char), _>("bad")), (Err("bad"), Err("bad")));
+/// ```
+pub fn unzip_result<T: TupleUnzip, E: Clone>(res: Result<T, E>) -> T::Results<E> {
+    T::unzip_result(res)
+}
+
+/// Take every element out of a tuple of `Option`s.
+pub trait TupleTakeAll: seal::Sealed {
+    /// [`Option::take`] each element, leaving `None` in its place, and return
+    /// the previous values.
+    /// ```rust
+    /// # use tuplestructops::TupleTakeAll;
+    /// let mut state = (Some(1), None::<char>, Some("x"));
+    /// assert_eq!(state.take_all(), (Some(1), None, Some("x")));
+    /// assert_eq!(state, (None, None, None));
+    /// ```
+    fn take_all(&mut self) -> Self;
+}
+
+/// Unwrap every element of a tuple of `Option`s, using the default value for
+/// `None`.
+pub trait TupleUnwrapOrDefault: seal::Sealed {
+    /// Tuple of unwrapped values.
+    type Output;
+
+    /// [`Option::unwrap_or_default`] each element.
+    /// ```rust
+    /// # use tuplestructops::TupleUnwrapOrDefault;
+    /// let state = (Some(1), None::<String>, Some('x'));
+    /// assert_eq!(state.unwrap_or_default_each(), (1, String::new(), 'x'));
+    /// ```
+    fn unwrap_or_default_each(self) -> Self::Output;
+}
+
+macro_rules! impl_transpose {
+    ($n:literal) => {
+        seq!(J in 0..$n {
+            // Transpose by value
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<#(T~J,)*> TupleTranspose for (#(Option<T~J>,)*) {
+                type Output = (#(T~J,)*);
+
+                #[allow(clippy::unused_unit, non_snake_case)]
+                fn transpose(self) -> Option<Self::Output> {
+                    let (#(T~J,)*) = self;
+
+                    Some((#(T~J?,)*))
+                }
+            }
+
+            // Transpose by reference
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<'a, #(T~J,)*> TupleTranspose for &'a (#(Option<T~J>,)*) {
+                type Output = (#(&'a T~J,)*);
+
+                #[allow(clippy::unused_unit, non_snake_case)]
+                fn transpose(self) -> Option<Self::Output> {
+                    let (#(T~J,)*) = self;
+
+                    Some((#(T~J.as_ref()?,)*))
+                }
+            }
+
+            // Collect by value
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<E, #(T~J,)*> TupleTryCollect<E> for (#(Result<T~J, E>,)*) {
+                type Output = (#(T~J,)*);
+
+                #[allow(clippy::unused_unit, non_snake_case)]
+                fn try_collect(self) -> Result<Self::Output, E> {
+                    let (#(T~J,)*) = self;
+
+                    Ok((#(T~J?,)*))
+                }
+            }
+
+            // Collect by reference
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<'a, E, #(T~J,)*> TupleTryCollect<&'a E> for &'a (#(Result<T~J, E>,)*) {
+                type Output = (#(&'a T~J,)*);
+
+                #[allow(clippy::unused_unit, non_snake_case)]
+                fn try_collect(self) -> Result<Self::Output, &'a E> {
+                    let (#(T~J,)*) = self;
+
+                    Ok((#(T~J.as_ref()?,)*))
+                }
+            }
+
+            // Validate by value
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<E, #(T~J,)*> TupleValidate<E> for (#(Result<T~J, E>,)*) {
+                type Output = (#(T~J,)*);
+
+                #[allow(clippy::unused_unit, non_snake_case, unused_mut)]
+                fn validate(self) -> Result<Self::Output, Vec<E>> {
+                    let (#(T~J,)*) = self;
+                    let mut errors = Vec::new();
+
+                    #(
+                        let T~J = match T~J {
+                            Ok(v) => Some(v),
+                            Err(e) => {
+                                errors.push(e);
+                                None
+                            }
+                        };
+                    )*
+
+                    match (#(T~J,)*) {
+                        (#(Some(T~J),)*) => Ok((#(T~J,)*)),
+                        #[allow(unreachable_patterns)]
+                        _ => Err(errors),
+                    }
+                }
+            }
+
+            // Validate by reference
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<'a, E, #(T~J,)*> TupleValidate<&'a E> for &'a (#(Result<T~J, E>,)*) {
+                type Output = (#(&'a T~J,)*);
+
+                #[allow(clippy::unused_unit, non_snake_case)]
+                fn validate(self) -> Result<Self::Output, Vec<&'a E>> {
+                    let (#(T~J,)*) = self;
+
+                    (#(T~J.as_ref(),)*).validate()
+                }
+            }
+
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<#(T~J,)*> TupleUnzip for (#(T~J,)*) {
+                type Options = (#(Option<T~J>,)*);
+                type Results<E> = (#(Result<T~J, E>,)*);
+
+                #[allow(clippy::unused_unit, non_snake_case)]
+                fn unzip_option(opt: Option<Self>) -> Self::Options {
+                    match opt {
+                        Some((#(T~J,)*)) => (#(Some(T~J),)*),
+                        None => (#(None::<T~J>,)*),
+                    }
+                }
+
+                #[allow(clippy::unused_unit, non_snake_case, unused_variables)]
+                fn unzip_result<E: Clone>(res: Result<Self, E>) -> Self::Results<E> {
+                    match res {
+                        Ok((#(T~J,)*)) => (#(Ok(T~J),)*),
+                        Err(err) => (#(Err::<T~J, _>(err.clone()),)*),
+                    }
+                }
+            }
+
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<#(T~J,)*> TupleTakeAll for (#(Option<T~J>,)*) {
+                #[allow(clippy::unused_unit, non_snake_case)]
+                fn take_all(&mut self) -> Self {
+                    let (#(T~J,)*) = self;
+
+                    (#(T~J.take(),)*)
+                }
+            }
+
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<#(T~J: Default,)*> TupleUnwrapOrDefault for (#(Option<T~J>,)*) {
+                type Output = (#(T~J,)*);
+
+                #[allow(clippy::unused_unit, non_snake_case)]
+                fn unwrap_or_default_each(self) -> Self::Output {
+                    let (#(T~J,)*) = self;
+
+                    (#(T~J.unwrap_or_default(),)*)
+                }
+            }
+        });
+    };
+}
+
+for_each_tuple!(impl_transpose);
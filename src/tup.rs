@@ -0,0 +1,125 @@
+use std::ops::{Add, Deref, DerefMut, Shr};
+
+use super::*;
+
+/// Wrapper for a tuple, with the crate's structural operations as inherent
+/// methods so they can be used without importing their traits.
+///
+/// `Tup` dereferences to the inner tuple, so its fields and other methods are
+/// also available. (The exception is `.0`, which is the inner tuple itself.)
+/// ```rust
+/// use tuplestructops::Tup;
+///
+/// let t = Tup((1, 'a')).join(("b", 2.5));
+/// assert_eq!(t.len(), 4);
+/// assert_eq!(*t.idx::<2>(), "b");
+/// assert_eq!(t.1, 'a');
+///
+/// let (left, right): (Tup<(_,)>, Tup<(_, _, _)>) = t.split();
+/// assert_eq!(left.into_inner(), (1,));
+/// assert_eq!(*right, ('a', "b", 2.5));
+/// ```
+/// It also has some operator sugar: `+` joins, and `>>` calls a function with
+/// the elements as arguments.
+/// ```rust
+/// use tuplestructops::Tup;
+///
+/// let args = Tup((1, 2)) + Tup((3,));
+/// assert_eq!(args >> |a, b, c| a + b * c, 7);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct Tup<T>(pub T);
+
+impl<T> Tup<T> {
+    /// Unwrap the inner tuple.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+
+    /// Join with another tuple. See [`TupleJoin::join`].
+    pub fn join<R>(self, other: R) -> Tup<TupleJoinOutput<T, R>>
+    where
+        T: TupleJoin<R>,
+    {
+        Tup(self.0.join(other))
+    }
+
+    /// Split into left and right portions. See [`TupleSplit::split`].
+    pub fn split<L, R>(self) -> (Tup<L>, Tup<R>)
+    where
+        T: TupleSplit<L, R>,
+    {
+        let (l, r) = self.0.split();
+        (Tup(l), Tup(r))
+    }
+
+    /// Reference to element `I`. See [`TupleIdx::idx`].
+    pub fn idx<'a, const I: usize>(&'a self) -> <&'a T as TupleIdx<I>>::Output
+    where
+        &'a T: TupleIdx<I>,
+    {
+        TupleIdx::<I>::idx(&self.0)
+    }
+
+    /// Number of elements. See [`TupleLen::len`].
+    pub fn len(&self) -> usize
+    where
+        T: TupleLen,
+    {
+        T::LEN
+    }
+
+    /// True if the tuple is the unit tuple. See [`TupleLen::is_empty`].
+    pub fn is_empty(&self) -> bool
+    where
+        T: TupleLen,
+    {
+        T::LEN == 0
+    }
+}
+
+impl<T> From<T> for Tup<T> {
+    fn from(tuple: T) -> Self {
+        Tup(tuple)
+    }
+}
+
+impl<T> Deref for Tup<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for Tup<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+/// `Tup(a) + Tup(b)` joins the tuples. See [`TupleJoin::join`].
+impl<L, R> Add<Tup<R>> for Tup<L>
+where
+    L: TupleJoin<R>,
+{
+    type Output = Tup<TupleJoinOutput<L, R>>;
+
+    fn add(self, other: Tup<R>) -> Self::Output {
+        Tup(self.0.join(other.0))
+    }
+}
+
+/// `Tup(args) >> f` calls `f` with the elements as arguments. See
+/// [`TupleCall::apply`].
+impl<T, F> Shr<F> for Tup<T>
+where
+    T: TupleCall<F>,
+{
+    type Output = T::Output;
+
+    fn shr(self, f: F) -> Self::Output {
+        self.0.apply(f)
+    }
+}
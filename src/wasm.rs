@@ -0,0 +1,112 @@
+//! Conversions between tuples and JavaScript arrays with
+//! [`wasm_bindgen`].
+//!
+//! [`TupleIntoJsArray`] converts tuples whose elements convert into `JsValue`
+//! into a [`js_sys::Array`], and [`TupleFromJsArray`] converts back, so
+//! functions exported to JavaScript can take and return tuple-shaped data:
+//! ```rust,no_run
+//! # use js_sys::Array;
+//! # use wasm_bindgen::prelude::*;
+//! # use tuplestructops::wasm::{TupleFromJsArray, TupleIntoJsArray};
+//! #[wasm_bindgen]
+//! pub fn swap(pair: Array) -> Result<Array, JsValue> {
+//!     let (a, b) = <(String, f64)>::from_js_array(&pair)?;
+//!     Ok((b, a).into_js_array())
+//! }
+//! ```
+use std::error::Error;
+use std::fmt;
+
+use ::js_sys::Array;
+use ::wasm_bindgen::JsValue;
+
+use super::*;
+
+/// Error from [`TupleFromJsArray::from_js_array`].
+#[derive(Debug, Clone)]
+pub enum FromJsArrayError {
+    /// The array had the wrong number of elements.
+    Count {
+        /// Number of elements in the tuple.
+        expected: usize,
+        /// Number of elements in the array.
+        found: usize,
+    },
+    /// An element couldn't be converted to the tuple's type at that position.
+    Elem {
+        /// Index of the element.
+        index: usize,
+        /// The value which failed to convert.
+        value: JsValue,
+    },
+}
+
+impl fmt::Display for FromJsArrayError {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FromJsArrayError::Count { expected, found } => {
+                write!(fmt, "expected {} elements, found {}", expected, found)
+            }
+            FromJsArrayError::Elem { index, value } => {
+                write!(fmt, "element {}: unexpected value {:?}", index, value)
+            }
+        }
+    }
+}
+
+impl Error for FromJsArrayError {}
+
+/// Converts to a JavaScript `TypeError`, so it can be returned from functions
+/// exported with `#[wasm_bindgen]`.
+impl From<FromJsArrayError> for JsValue {
+    fn from(err: FromJsArrayError) -> Self {
+        ::js_sys::TypeError::new(&err.to_string()).into()
+    }
+}
+
+/// Conversion of tuples into JavaScript arrays.
+pub trait TupleIntoJsArray: seal::Sealed {
+    /// Convert each element into a `JsValue`, and collect them into an array.
+    fn into_js_array(self) -> Array;
+}
+
+/// Conversion of JavaScript arrays into tuples.
+pub trait TupleFromJsArray: seal::Sealed + Sized {
+    /// Convert each element of `array` into the corresponding element of the
+    /// tuple. The array must have exactly as many elements as the tuple.
+    fn from_js_array(array: &Array) -> Result<Self, FromJsArrayError>;
+}
+
+macro_rules! impl_js_array {
+    ($n:literal) => {
+        seq!(J in 0..$n {
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<#(T~J: Into<JsValue>,)*> TupleIntoJsArray for (#(T~J,)*) {
+                fn into_js_array(self) -> Array {
+                    let array: Array = Array::new();
+                    #(array.push(&self.J.into());)*
+                    array
+                }
+            }
+
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<#(T~J: TryFrom<JsValue>,)*> TupleFromJsArray for (#(T~J,)*) {
+                #[allow(unused_mut, unused_variables, clippy::unused_unit)]
+                fn from_js_array(array: &Array) -> Result<Self, FromJsArrayError> {
+                    let elems = array.to_vec();
+                    if elems.len() != $n {
+                        return Err(FromJsArrayError::Count { expected: $n, found: elems.len() });
+                    }
+                    let mut elems = elems.into_iter();
+                    Ok((#({
+                        let value = elems.next().unwrap();
+                        T~J::try_from(value.clone())
+                            .map_err(|_| FromJsArrayError::Elem { index: J, value })?
+                    },)*))
+                }
+            }
+        });
+    };
+}
+
+for_each_tuple!(impl_js_array);
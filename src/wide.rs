@@ -0,0 +1,204 @@
+//! Standard trait operations for tuples of any supported size.
+//!
+//! The standard library only implements traits such as `Default` for tuples of
+//! up to 12 elements, which doesn't cover the wider tuples enabled by the
+//! `tuple_24` and `tuple_32` features. These traits provide the same operations
+//! for every size, and [`Ordered`] wraps a tuple to implement the comparison
+//! and hashing traits.
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
+
+use super::*;
+
+/// Construct a tuple with the default value of each element.
+pub trait TupleDefault: seal::Sealed {
+    /// Tuple of default values.
+    fn default_tuple() -> Self;
+}
+
+/// Construct a tuple of any supported size with the default value of each
+/// element.
+/// ```rust
+/// # use tuplestructops::default_tuple;
+/// let t = default_tuple::<(u8, String, bool, f32, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8)>();
+/// assert_eq!((t.0, t.1.as_str(), t.2, t.13), (0, "", false, 0));
+/// ```
+pub fn default_tuple<T: TupleDefault>() -> T {
+    T::default_tuple()
+}
+
+/// Elementwise equality for tuples of any size.
+pub trait TuplePartialEq: seal::Sealed {
+    /// True if every element is equal to the corresponding element of `other`.
+    fn eq_tuple(&self, other: &Self) -> bool;
+}
+
+/// Marker for tuples whose elements are all `Eq`.
+pub trait TupleEq: TuplePartialEq {}
+
+/// Lexicographic partial ordering for tuples of any size.
+pub trait TuplePartialOrd: TuplePartialEq {
+    /// Compare elementwise, returning the first non-equal result.
+    fn partial_cmp_tuple(&self, other: &Self) -> Option<Ordering>;
+}
+
+/// Lexicographic total ordering for tuples of any size.
+pub trait TupleOrd: TupleEq + TuplePartialOrd {
+    /// Compare elementwise, returning the first non-equal result.
+    fn cmp_tuple(&self, other: &Self) -> Ordering;
+}
+
+/// Hashing for tuples of any size.
+pub trait TupleHash: seal::Sealed {
+    /// Hash each element in order.
+    fn hash_tuple<H: Hasher>(&self, state: &mut H);
+}
+
+/// Wrapper which implements the comparison and hashing traits for tuples of any
+/// size, using the same lexicographic semantics as the standard library. This
+/// allows wide tuples to be used as map keys, for example.
+/// ```rust
+/// # use std::collections::{BTreeSet, HashSet};
+/// # use tuplestructops::wide::Ordered;
+/// type Wide = (u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, &'static str);
+/// let a: Wide = (0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, "a");
+/// let b: Wide = (0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, "b");
+///
+/// assert!(Ordered(a) < Ordered(b));
+/// let set: BTreeSet<_> = [Ordered(b), Ordered(a), Ordered(b)].into_iter().collect();
+/// assert_eq!(set.len(), 2);
+/// let set: HashSet<_> = [Ordered(b), Ordered(a), Ordered(b)].into_iter().collect();
+/// assert_eq!(set.len(), 2);
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Ordered<T>(pub T);
+
+impl<T: TuplePartialEq> PartialEq for Ordered<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.eq_tuple(&other.0)
+    }
+}
+
+impl<T: TupleEq> Eq for Ordered<T> {}
+
+impl<T: TuplePartialOrd> PartialOrd for Ordered<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.0.partial_cmp_tuple(&other.0)
+    }
+}
+
+impl<T: TupleOrd> Ord for Ordered<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.cmp_tuple(&other.0)
+    }
+}
+
+impl<T: TupleHash> Hash for Ordered<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash_tuple(state)
+    }
+}
+
+/// Clone the elements of a tuple into an owned tuple.
+pub trait TupleClone: seal::Sealed {
+    /// Owned tuple.
+    type Output;
+
+    /// Clone each element. This is implemented for tuples by reference, and
+    /// for tuples of references such as those returned by the by-reference
+    /// operations.
+    /// ```rust
+    /// # use tuplestructops::{TupleClone, TupleSplit};
+    /// let t = (1, String::from("a"), 'b');
+    /// assert_eq!(t.clone_tuple(), t);
+    ///
+    /// let (_, tail): (_, (&String, &char)) = (&t).split();
+    /// assert_eq!(tail.clone_tuple(), (String::from("a"), 'b'));
+    /// ```
+    fn clone_tuple(self) -> Self::Output;
+}
+
+macro_rules! impl_wide {
+    ($n:literal) => {
+        seq!(J in 0..$n {
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<#(T~J: Default,)*> TupleDefault for (#(T~J,)*) {
+                #[allow(clippy::unused_unit)]
+                fn default_tuple() -> Self {
+                    (#(T~J::default(),)*)
+                }
+            }
+
+            // Clone by reference
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<'a, #(T~J: Clone,)*> TupleClone for &'a (#(T~J,)*) {
+                type Output = (#(T~J,)*);
+
+                #[allow(clippy::unused_unit)]
+                fn clone_tuple(self) -> Self::Output {
+                    (#(self.J.clone(),)*)
+                }
+            }
+
+            // Clone a tuple of references
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<'a, #(T~J: Clone,)*> TupleClone for (#(&'a T~J,)*) {
+                type Output = (#(T~J,)*);
+
+                #[allow(clippy::unused_unit)]
+                fn clone_tuple(self) -> Self::Output {
+                    (#(self.J.clone(),)*)
+                }
+            }
+
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<#(T~J: PartialEq,)*> TuplePartialEq for (#(T~J,)*) {
+                #[allow(unused_variables)]
+                fn eq_tuple(&self, other: &Self) -> bool {
+                    true #(&& self.J == other.J)*
+                }
+            }
+
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<#(T~J: Eq,)*> TupleEq for (#(T~J,)*) {}
+
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<#(T~J: PartialOrd,)*> TuplePartialOrd for (#(T~J,)*) {
+                #[allow(unused_variables)]
+                fn partial_cmp_tuple(&self, other: &Self) -> Option<Ordering> {
+                    #(
+                        match self.J.partial_cmp(&other.J) {
+                            Some(Ordering::Equal) => {}
+                            ord => return ord,
+                        }
+                    )*
+                    Some(Ordering::Equal)
+                }
+            }
+
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<#(T~J: Ord,)*> TupleOrd for (#(T~J,)*) {
+                #[allow(unused_variables)]
+                fn cmp_tuple(&self, other: &Self) -> Ordering {
+                    #(
+                        match self.J.cmp(&other.J) {
+                            Ordering::Equal => {}
+                            ord => return ord,
+                        }
+                    )*
+                    Ordering::Equal
+                }
+            }
+
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<#(T~J: Hash,)*> TupleHash for (#(T~J,)*) {
+                #[allow(unused_variables)]
+                fn hash_tuple<H: Hasher>(&self, state: &mut H) {
+                    #(self.J.hash(state);)*
+                }
+            }
+        });
+    };
+}
+
+for_each_tuple!(impl_wide);
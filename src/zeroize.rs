@@ -0,0 +1,88 @@
+//! Scrubbing secrets held in tuples of any size with [`zeroize`](::zeroize).
+//!
+//! zeroize only implements `Zeroize` for tuples of up to 10 elements.
+//! [`TupleZeroize`] zeroizes every element of a tuple of any size, and
+//! [`ZeroizingTuple`] holds a tuple which is zeroized when it's dropped.
+//! ```rust
+//! # use tuplestructops::zeroize::{TupleZeroize, ZeroizingTuple};
+//! let mut creds = (String::from("user"), String::from("hunter2"), [1u8; 16]);
+//! creds.zeroize_each();
+//! assert_eq!(creds, (String::new(), String::new(), [0; 16]));
+//!
+//! let key = ZeroizingTuple::new((vec![1u8, 2, 3], 42u64));
+//! assert_eq!(key.1, 42);
+//! // Both elements are zeroized here when `key` is dropped.
+//! ```
+use std::ops::{Deref, DerefMut};
+
+use ::zeroize::{Zeroize, ZeroizeOnDrop};
+
+use super::*;
+
+/// Zeroize each element of a tuple.
+pub trait TupleZeroize: seal::Sealed {
+    /// Zeroize every element in place, using its `Zeroize` impl.
+    fn zeroize_each(&mut self);
+}
+
+/// Wrapper for a tuple which zeroizes every element when it's dropped.
+///
+/// This is like zeroize's `Zeroizing`, but for tuples of any size.
+/// It dereferences to the inner tuple.
+pub struct ZeroizingTuple<T: TupleZeroize>(T);
+
+impl<T: TupleZeroize> ZeroizingTuple<T> {
+    /// Wrap a tuple to be zeroized on drop.
+    pub fn new(tuple: T) -> Self {
+        ZeroizingTuple(tuple)
+    }
+}
+
+impl<T: TupleZeroize> From<T> for ZeroizingTuple<T> {
+    fn from(tuple: T) -> Self {
+        ZeroizingTuple(tuple)
+    }
+}
+
+impl<T: TupleZeroize> Deref for ZeroizingTuple<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T: TupleZeroize> DerefMut for ZeroizingTuple<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+impl<T: TupleZeroize> Zeroize for ZeroizingTuple<T> {
+    fn zeroize(&mut self) {
+        self.0.zeroize_each()
+    }
+}
+
+impl<T: TupleZeroize> Drop for ZeroizingTuple<T> {
+    fn drop(&mut self) {
+        self.0.zeroize_each()
+    }
+}
+
+impl<T: TupleZeroize> ZeroizeOnDrop for ZeroizingTuple<T> {}
+
+macro_rules! impl_zeroize {
+    ($n:literal) => {
+        seq!(J in 0..$n {
+            #[cfg_attr(not(feature = "impl_docs"), doc(hidden))]
+            impl<#(T~J: Zeroize,)*> TupleZeroize for (#(T~J,)*) {
+                fn zeroize_each(&mut self) {
+                    #(self.J.zeroize();)*
+                }
+            }
+        });
+    };
+}
+
+for_each_tuple!(impl_zeroize);
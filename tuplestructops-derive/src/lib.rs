@@ -0,0 +1,124 @@
+//! Derive macros for [tuplestructops](https://docs.rs/tuplestructops). These
+//! are re-exported by tuplestructops with its `derive` feature, and shouldn't
+//! be used directly.
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, Data, DeriveInput, Error, Fields, Index};
+
+/// Implement `tuplestructops::TupleOps` for a struct, converting it to and
+/// from the tuple of its field types in declaration order.
+#[proc_macro_derive(TupleOps)]
+pub fn derive_tuple_ops(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    tuple_ops(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// Implement `tuplestructops::TupleLike` and `tuplestructops::TupleOps` for a
+/// newtype wrapper around a tuple, by delegating to the inner tuple.
+#[proc_macro_derive(TupleLike)]
+pub fn derive_tuple_like(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    tuple_like(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn tuple_ops(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let fields = match &input.data {
+        Data::Struct(data) => &data.fields,
+        _ => {
+            return Err(Error::new_spanned(
+                input,
+                "TupleOps can only be derived for structs",
+            ))
+        }
+    };
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let types = fields.iter().map(|field| &field.ty);
+    let vars: Vec<_> = (0..fields.len())
+        .map(|idx| format_ident!("field{}", idx))
+        .collect();
+
+    let (into_pat, from_expr) = match fields {
+        Fields::Named(_) => {
+            let names: Vec<_> = fields.iter().map(|field| &field.ident).collect();
+            (
+                quote!(#name { #(#names: #vars,)* }),
+                quote!(#name { #(#names: #vars,)* }),
+            )
+        }
+        Fields::Unnamed(_) => {
+            let idxs = (0..fields.len()).map(Index::from);
+            (
+                quote!(#name { #(#idxs: #vars,)* }),
+                quote!(#name(#(#vars,)*)),
+            )
+        }
+        Fields::Unit => (quote!(#name), quote!(#name)),
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::tuplestructops::TupleOps for #name #ty_generics #where_clause {
+            type Tuple = (#(#types,)*);
+
+            #[allow(clippy::unused_unit)]
+            fn into_tuple(self) -> Self::Tuple {
+                let #into_pat = self;
+                (#(#vars,)*)
+            }
+
+            fn from_tuple((#(#vars,)*): Self::Tuple) -> Self {
+                #from_expr
+            }
+        }
+    })
+}
+
+fn tuple_like(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let field = match &input.data {
+        Data::Struct(data) if data.fields.len() == 1 => data.fields.iter().next().unwrap(),
+        _ => {
+            return Err(Error::new_spanned(
+                input,
+                "TupleLike can only be derived for structs with exactly one field",
+            ))
+        }
+    };
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let ty = &field.ty;
+    let member = match &field.ident {
+        Some(ident) => quote!(#ident),
+        None => quote!(0),
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::tuplestructops::TupleOps for #name #ty_generics #where_clause {
+            type Tuple = #ty;
+
+            fn into_tuple(self) -> Self::Tuple {
+                self.#member
+            }
+
+            fn from_tuple(tuple: Self::Tuple) -> Self {
+                #name { #member: tuple }
+            }
+        }
+
+        impl #impl_generics ::tuplestructops::TupleLike for #name #ty_generics #where_clause {
+            fn as_tuple(&self) -> &Self::Tuple {
+                &self.#member
+            }
+
+            fn as_tuple_mut(&mut self) -> &mut Self::Tuple {
+                &mut self.#member
+            }
+        }
+    })
+}